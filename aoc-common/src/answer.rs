@@ -0,0 +1,78 @@
+//! Typed answers, so runners can report them as more than just text.
+
+use std::fmt;
+use std::time::Duration;
+use crate::solution::Part;
+
+/// A puzzle answer, along with any extra details worth reporting alongside it.
+pub trait Answer: fmt::Display {
+    /// The answer alone, as it would be entered on the puzzle page.
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    /// Named details about how the answer was found, e.g. day 11's flash count.
+    fn metadata(&self) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+}
+
+macro_rules! plain_answers {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {})*
+    };
+}
+plain_answers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String);
+
+/// What solving a part returns: an answer, or for parts that can fail on input that parses (e.g.
+/// day 2's submarine going out of range), a `Result` of one.
+pub trait IntoAnswer {
+    type Answer: Answer;
+
+    /// The answer, or a description of why there isn't one.
+    fn into_answer(self) -> Result<Self::Answer, String>;
+}
+
+impl<T: Answer> IntoAnswer for T {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<T, String> {
+        Ok(self)
+    }
+}
+
+impl<T: Answer, E: fmt::Display> IntoAnswer for Result<T, E> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<T, String> {
+        self.map_err(|e| e.to_string())
+    }
+}
+
+/// The outcome of solving one part of a day's puzzle.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct PartResult {
+    pub part: Part,
+    /// The answer as the day displays it, which may include some of its metadata.
+    pub display: String,
+    /// The answer alone (see `Answer::value`).
+    pub value: String,
+    pub metadata: Vec<(&'static str, String)>,
+    /// How long parsing the input took.
+    pub parse_elapsed: Duration,
+    /// How long solving the part took, excluding parsing.
+    pub elapsed: Duration,
+}
+
+impl PartResult {
+    pub fn new(part: Part, answer: &impl Answer, parse_elapsed: Duration, elapsed: Duration) -> PartResult {
+        PartResult {
+            part,
+            display: answer.to_string(),
+            value: answer.value(),
+            metadata: answer.metadata(),
+            parse_elapsed,
+            elapsed,
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Timing of each phase of a day's solution over repeated runs.
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use crate::answer::IntoAnswer;
+use crate::solution::{Part, Solution, SolveError};
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The duration of every run of one phase.
+pub struct Timings {
+    pub phase: Phase,
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    fn new(phase: Phase) -> Timings {
+        Timings { phase, samples: vec![] }
+    }
+
+    pub fn stats(&self) -> Stats {
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+        Stats {
+            iterations: sorted.len(),
+            min: sorted[0],
+            // The upper median, for an even number of samples
+            median: sorted[sorted.len() / 2],
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+/// Times parsing and each part of a day's puzzle `iterations` times, returning the timings of each
+/// phase in order. Each part is timed against a fresh parse of the input, and must have an answer
+/// for it.
+pub type Bencher = fn(&str, usize) -> Result<Vec<Timings>, SolveError>;
+
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<Vec<Timings>, SolveError> {
+    assert!(iterations > 0, "Must bench at least one iteration");
+
+    let mut parse = Timings::new(Phase::Parse);
+    let mut part1 = Timings::new(Phase::Part1);
+    let mut part2 = Timings::new(Phase::Part2);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input)).map_err(|e| e.locate(input))?;
+        parse.samples.push(start.elapsed());
+
+        let start = Instant::now();
+        let answer = black_box(S::part1(black_box(&parsed)));
+        part1.samples.push(start.elapsed());
+        answer.into_answer().map_err(|message| SolveError::Part(Part::One, message))?;
+
+        if S::HAS_PART_2 {
+            let start = Instant::now();
+            let answer = black_box(S::part2(black_box(&parsed)));
+            part2.samples.push(start.elapsed());
+            answer.into_answer().map_err(|message| SolveError::Part(Part::Two, message))?;
+        }
+    }
+
+    let mut timings = vec![parse, part1];
+    if S::HAS_PART_2 {
+        timings.push(part2);
+    }
+    Ok(timings)
+}
+
+/// Formats a duration to 3 significant figures in the most readable unit, e.g. "12.3ms".
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+    let decimals = if value < 10.0 { 2 } else if value < 100.0 { 1 } else { 0 };
+    format!("{:.*}{}", decimals, value, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseResult;
+    use crate::solution::NoPuzzle;
+
+    struct Lines;
+    impl Solution for Lines {
+        type Input = Vec<String>;
+        type Answer1 = usize;
+        type Answer2 = NoPuzzle;
+        const HAS_PART_2: bool = false;
+
+        fn parse(input: &str) -> ParseResult<Vec<String>> {
+            Ok(input.lines().map(|line| line.to_string()).collect())
+        }
+
+        fn part1(lines: &Vec<String>) -> usize {
+            lines.len()
+        }
+
+        fn part2(_lines: &Vec<String>) -> NoPuzzle {
+            NoPuzzle
+        }
+    }
+
+    #[test]
+    fn test_bench_times_each_phase() {
+        let timings = bench::<Lines>("a\nb", 5).unwrap();
+        let phases = timings.iter().map(|t| t.phase).collect::<Vec<_>>();
+        assert_eq!(phases, vec![Phase::Parse, Phase::Part1]);
+        assert!(timings.iter().all(|t| t.stats().iterations == 5));
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut timings = Timings::new(Phase::Parse);
+        timings.samples = [30, 10, 20, 50].into_iter().map(Duration::from_millis).collect();
+        let stats = timings.stats();
+        assert_eq!(stats.min, Duration::from_millis(10));
+        assert_eq!(stats.median, Duration::from_millis(30));
+        assert_eq!(stats.max, Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(950)), "950ns");
+        assert_eq!(format_duration(Duration::from_micros(1234)), "1.23ms");
+        assert_eq!(format_duration(Duration::from_millis(45_600)), "45.6s");
+    }
+}
@@ -0,0 +1,105 @@
+//! Coordinate types used by the grid- and space-based puzzles.
+
+/// A position on an unbounded 2D plane, which may be negative.
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub struct Coord {
+    pub x: isize,
+    pub y: isize,
+}
+impl Coord {
+    pub fn new(x: isize, y: isize) -> Coord {
+        Coord { x, y }
+    }
+
+    pub fn offset(&self, dx: isize, dy: isize) -> Coord {
+        Coord::new(self.x + dx, self.y + dy)
+    }
+}
+
+/// A position within a bounded grid, indexed from the top-left corner.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+impl Pos {
+    pub fn new(x: usize, y: usize) -> Pos {
+        Pos { x, y }
+    }
+
+    /// The orthogonally adjacent positions (up, right, down, left) that lie within a grid of the
+    /// given dimensions.
+    pub fn neighbours(&self, width: usize, height: usize) -> impl Iterator<Item = Pos> {
+        let Pos { x, y } = *self;
+        [
+            y.checked_sub(1).map(|y| Pos::new(x, y)),
+            Some(Pos::new(x + 1, y)).filter(|p| p.x < width),
+            Some(Pos::new(x, y + 1)).filter(|p| p.y < height),
+            x.checked_sub(1).map(|x| Pos::new(x, y)),
+        ].into_iter().flatten()
+    }
+}
+
+/// A point in 3D space.
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+impl Point {
+    pub fn new(x: i32, y: i32, z: i32) -> Point {
+        Point { x, y, z }
+    }
+
+    pub fn origin() -> Point {
+        Point::new(0, 0, 0)
+    }
+
+    /// The offset which, when applied to this point, gives `other`.
+    pub fn translation_needed_to(&self, other: &Point) -> Point {
+        Point {
+            x: other.x - self.x,
+            y: other.y - self.y,
+            z: other.z - self.z,
+        }
+    }
+
+    pub fn translate(&self, other: &Point) -> Point {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+
+    /// The Manhattan distance between the two points.
+    pub fn dist_to(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pos_neighbours_in_corner() {
+        let neighbours = Pos::new(0, 0).neighbours(3, 3).collect::<Vec<_>>();
+        assert_eq!(neighbours, vec![Pos::new(1, 0), Pos::new(0, 1)]);
+    }
+
+    #[test]
+    fn test_pos_neighbours_in_middle() {
+        let neighbours = Pos::new(1, 1).neighbours(3, 3).collect::<Vec<_>>();
+        assert_eq!(neighbours, vec![Pos::new(1, 0), Pos::new(2, 1), Pos::new(1, 2), Pos::new(0, 1)]);
+    }
+
+    #[test]
+    fn test_point_translation_round_trips() {
+        let a = Point::new(-618, -824, -621);
+        let b = Point::new(686, 422, 578);
+        assert_eq!(a.translate(&a.translation_needed_to(&b)), b);
+        assert_eq!(a.dist_to(&b), 1304 + 1246 + 1199);
+    }
+}
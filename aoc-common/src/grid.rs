@@ -0,0 +1,254 @@
+//! A rectangular grid of cells, addressed by `Pos` with (0, 0) at the top left.
+
+use std::ops::{Index, IndexMut};
+use crate::coord::Pos;
+use crate::parse::{digit_rows, ParseError, ParseResult};
+
+// Offsets to the 8 surrounding cells, starting above and going clockwise
+const OFFSETS_8: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+// Offsets to the 4 orthogonally adjacent cells, in the same order as `Pos::neighbours`
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> where T: Clone {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Builds a grid from its rows, which must all be the same (non-zero) length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first()?.len();
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let height = rows.len();
+        Some(Grid { width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    /// Parses one cell per char, one row per line. `cell` returns None for chars that aren't
+    /// valid cells, which are reported as `expected`.
+    pub fn parse_chars(input: &str, expected: &str, cell: impl Fn(char) -> Option<T>) -> ParseResult<Grid<T>> {
+        let rows = input.lines().map(|line| {
+            line.char_indices().map(|(i, c)| {
+                cell(c).ok_or_else(|| ParseError::at(&line[i..i + c.len_utf8()], format!("Expected {}", expected)))
+            }).collect::<ParseResult<Vec<T>>>()
+        }).collect::<ParseResult<Vec<_>>>()?;
+        check_rectangular(input)?;
+        Grid::from_rows(rows).ok_or_else(|| ParseError::new("Expected at least one row of cells"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: &Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: &Pos) -> Option<&T> {
+        if self.contains(pos) { Some(&self.cells[self.index_of(pos)]) } else { None }
+    }
+
+    pub fn get_mut(&mut self, pos: &Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            let index = self.index_of(pos);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    pub fn swap(&mut self, a: &Pos, b: &Pos) {
+        let (a, b) = (self.index_of(a), self.index_of(b));
+        self.cells.swap(a, b);
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[(y * self.width)..((y + 1) * self.width)]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// The in-bounds cells above, right, below and left of `pos`.
+    pub fn neighbours4(&self, pos: &Pos) -> impl Iterator<Item = Pos> + '_ {
+        let pos = *pos;
+        OFFSETS_4.iter().filter_map(move |&(dx, dy)| self.offset(&pos, dx, dy))
+    }
+
+    /// The in-bounds cells surrounding `pos`, including diagonals.
+    pub fn neighbours8(&self, pos: &Pos) -> impl Iterator<Item = Pos> + '_ {
+        let pos = *pos;
+        OFFSETS_8.iter().filter_map(move |&(dx, dy)| self.offset(&pos, dx, dy))
+    }
+
+    /// As `neighbours4`, but wrapping around the edges as if the grid were a torus.
+    pub fn wrapping_neighbours4(&self, pos: &Pos) -> impl Iterator<Item = Pos> + '_ {
+        let pos = *pos;
+        OFFSETS_4.iter().map(move |&(dx, dy)| self.wrapping_offset(&pos, dx, dy))
+    }
+
+    /// As `neighbours8`, but wrapping around the edges as if the grid were a torus.
+    pub fn wrapping_neighbours8(&self, pos: &Pos) -> impl Iterator<Item = Pos> + '_ {
+        let pos = *pos;
+        OFFSETS_8.iter().map(move |&(dx, dy)| self.wrapping_offset(&pos, dx, dy))
+    }
+
+    /// The position `dx` across and `dy` down from `pos`, if that's within the grid.
+    pub fn offset(&self, pos: &Pos, dx: isize, dy: isize) -> Option<Pos> {
+        let x = pos.x.checked_add_signed(dx)?;
+        let y = pos.y.checked_add_signed(dy)?;
+        let pos = Pos::new(x, y);
+        if self.contains(&pos) { Some(pos) } else { None }
+    }
+
+    /// The position `dx` across and `dy` down from `pos`, wrapping around the edges.
+    pub fn wrapping_offset(&self, pos: &Pos, dx: isize, dy: isize) -> Pos {
+        let x = (pos.x as isize + dx).rem_euclid(self.width as isize) as usize;
+        let y = (pos.y as isize + dy).rem_euclid(self.height as isize) as usize;
+        Pos::new(x, y)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    fn index_of(&self, pos: &Pos) -> usize {
+        assert!(self.contains(pos), "{:?} is outside the {}x{} grid", pos, self.width, self.height);
+        pos.y * self.width + pos.x
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a block of single decimal digits, one row per line.
+    pub fn parse_digits(input: &str) -> ParseResult<Grid<u32>> {
+        let rows = digit_rows(input)?;
+        // The rows are known to be rectangular, so this only fails if they're empty
+        Grid::from_rows(rows).ok_or_else(|| ParseError::new("Expected at least one digit per row"))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        &self.cells[self.index_of(&pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let index = self.index_of(&pos);
+        &mut self.cells[index]
+    }
+}
+
+fn check_rectangular(input: &str) -> ParseResult<()> {
+    let width = input.lines().next().map_or(0, |line| line.chars().count());
+    match input.lines().find(|line| line.chars().count() != width) {
+        Some(line) => Err(ParseError::at(line, format!("Expected {} cells, as on the first line", width))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<u32> {
+        Grid::parse_digits("123\n456").unwrap()
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(2, 1)], 6);
+        assert_eq!(grid.get(&Pos::new(3, 0)), None);
+        assert_eq!(Grid::parse_digits("\n").map(|_| ()), Err(ParseError::new("Expected at least one digit per row")));
+    }
+
+    #[test]
+    fn test_parse_chars() {
+        let input = "#.\n.#";
+        let grid = Grid::parse_chars(input, "'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }).unwrap();
+        assert_eq!(grid.iter().filter(|(_, &lit)| lit).count(), 2);
+
+        let input = "#.\n.x";
+        let error = Grid::<bool>::parse_chars(input, "'#' or '.'", |c| (c == '#').then_some(true))
+            .unwrap_err().locate(input);
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (1, 2));
+
+        let input = "#.\n#";
+        let error = Grid::parse_chars(input, "'#'", |_| Some(())).unwrap_err().locate(input);
+        assert_eq!(error.location.unwrap().line, 2);
+    }
+
+    #[test]
+    fn test_neighbours_are_bounds_checked() {
+        let grid = example();
+        let corner = grid.neighbours4(&Pos::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, vec![Pos::new(1, 0), Pos::new(0, 1)]);
+        assert_eq!(grid.neighbours8(&Pos::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_wrapping_neighbours() {
+        let grid = example();
+        let wrapped = grid.wrapping_neighbours4(&Pos::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(wrapped, vec![Pos::new(0, 1), Pos::new(1, 0), Pos::new(0, 1), Pos::new(2, 0)]);
+        assert_eq!(grid.wrapping_offset(&Pos::new(2, 1), 1, 1), Pos::new(0, 0));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = example();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    }
+}
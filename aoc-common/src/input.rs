@@ -0,0 +1,32 @@
+//! Loading of puzzle input files.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+/// Reads the whole of the file at `path` into a string.
+pub fn read_to_string(path: impl AsRef<Path>) -> String {
+    let path = path.as_ref();
+    std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Could not read {}: {}", path.display(), e))
+}
+
+/// Iterates over the lines of the file at `path`, without their line endings.
+pub fn lines(path: &str) -> impl Iterator<Item = String> {
+    let file = File::open(path)
+        .unwrap_or_else(|e| panic!("Could not open {}: {}", path, e));
+    BufReader::new(file)
+        .lines()
+        .map(|line| line.expect("Could not read line"))
+}
+
+/// Reads puzzle input from the file at `path`, or from stdin if `path` is "-".
+pub fn read(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
@@ -0,0 +1,17 @@
+//! Code shared between the individual days' solutions.
+
+pub mod answer;
+pub mod bench;
+pub mod coord;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod render;
+pub mod search;
+pub mod solution;
+pub mod trace;
+
+pub use answer::Answer;
+pub use grid::Grid;
+pub use parse::{ParseError, ParseResult};
+pub use solution::{Part, Solution};
@@ -0,0 +1,143 @@
+//! Recoverable errors for malformed puzzle input.
+
+use std::fmt;
+use std::str::FromStr;
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// Where in the input an error was found. Lines and columns count from 1.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub line_text: String,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ParseError {
+    pub message: String,
+    pub text: Option<String>,
+    pub location: Option<Location>,
+    // Start address of the offending text, which is resolved to a location once the whole input is
+    // available (see `locate`), so parsers don't need to pass the full input around
+    address: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> ParseError {
+        ParseError { message: message.into(), text: None, location: None, address: None }
+    }
+
+    // `text` should be a slice of the input being parsed, so that it can later be located
+    pub fn at(text: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            text: Some(text.to_string()),
+            location: None,
+            address: Some(text.as_ptr() as usize),
+        }
+    }
+
+    /// Resolves the line and column of the offending text, if it was sliced from `input`.
+    pub fn locate(mut self, input: &str) -> ParseError {
+        let start = input.as_ptr() as usize;
+        if let Some(address) = self.address {
+            if self.location.is_none() && (start..=start + input.len()).contains(&address) {
+                let offset = address - start;
+                let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+                let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+                self.location = Some(Location {
+                    line: input[..offset].matches('\n').count() + 1,
+                    column: input[line_start..offset].chars().count() + 1,
+                    line_text: input[line_start..line_end].to_string(),
+                });
+            }
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(location) = &self.location {
+            write!(f, "line {}, column {}: ", location.line, location.column)?;
+        }
+        write!(f, "{}", self.message)?;
+        match (&self.text, &self.location) {
+            (_, Some(location)) => {
+                let marker_len = self.text.as_ref().map_or(1, |t| t.chars().count().max(1));
+                write!(f, "\n    {}\n    {}{}",
+                       location.line_text,
+                       " ".repeat(location.column - 1),
+                       "^".repeat(marker_len))
+            },
+            (Some(text), None) => write!(f, " ('{}')", text),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `text` (a slice of the input), describing it as `what` on failure.
+pub fn parse_at<T: FromStr>(text: &str, what: &str) -> ParseResult<T> {
+    text.parse().map_err(|_| ParseError::at(text, format!("Could not parse {}", what)))
+}
+
+/// Splits `text` (a slice of the input) around the first `delimiter`, describing it as `what` on
+/// failure.
+pub fn split_once_at<'a>(text: &'a str, delimiter: &str, what: &str) -> ParseResult<(&'a str, &'a str)> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(text, format!("Could not find '{}' in {}", delimiter, what)))
+}
+
+/// Parses a rectangular block of single decimal digits, one row per line.
+pub fn digit_rows(input: &str) -> ParseResult<Vec<Vec<u32>>> {
+    let rows = input.lines().map(|line| {
+        line.char_indices().map(|(i, c)| {
+            c.to_digit(10).ok_or_else(|| ParseError::at(&line[i..i + c.len_utf8()], "Expected a digit"))
+        }).collect::<ParseResult<Vec<u32>>>()
+    }).collect::<ParseResult<Vec<_>>>()?;
+
+    if let Some(first) = rows.first() {
+        let width = first.len();
+        if let Some(line) = input.lines().find(|line| line.chars().count() != width) {
+            return Err(ParseError::at(line, format!("Expected {} digits, as on the first line", width)));
+        }
+    } else {
+        return Err(ParseError::new("Expected at least one row of digits"));
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locates_text_within_input() {
+        let input = "forward 5\ndown x\nup 3";
+        let bad = &input[15..16];
+        let error = parse_at::<u32>(bad, "distance").unwrap_err().locate(input);
+        let location = error.location.clone().unwrap();
+        assert_eq!((location.line, location.column), (2, 6));
+        assert_eq!(location.line_text, "down x");
+        assert_eq!(error.to_string(), "line 2, column 6: Could not parse distance\n    down x\n         ^");
+    }
+
+    #[test]
+    fn test_digit_rows_rejects_ragged_lines() {
+        let input = "123\n45\n678";
+        let error = digit_rows(input).unwrap_err().locate(input);
+        assert_eq!(error.location.unwrap().line, 2);
+    }
+
+    #[test]
+    fn test_does_not_locate_text_from_elsewhere() {
+        let input = "1,2,3";
+        let owned = String::from("x");
+        let error = ParseError::at(&owned, "Bad").locate(input);
+        assert_eq!(error.location, None);
+        assert_eq!(error.to_string(), "Bad ('x')");
+    }
+}
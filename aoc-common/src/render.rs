@@ -0,0 +1,159 @@
+//! Drawing of simulations frame by frame. Days produce frames of abstract levels, which a
+//! `Palette` turns into colours, so that how a frame is coloured can be chosen when it's exported.
+
+use crate::grid::Grid;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+
+/// One step of a simulation: a rectangle of cells, each with a level from 0 to `levels - 1`.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    levels: u8,
+    cells: Vec<u8>,
+}
+
+impl Frame {
+    /// A frame with every cell at level 0.
+    pub fn new(width: usize, height: usize, levels: u8) -> Frame {
+        assert!(levels >= 2, "A frame needs at least 2 levels");
+        Frame { width, height, levels, cells: vec![0; width * height] }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, levels: u8, level: impl Fn(&T) -> u8) -> Frame {
+        let mut frame = Frame::new(grid.width(), grid.height(), levels);
+        for (pos, cell) in grid.iter() {
+            frame.set(pos.x, pos.y, level(cell));
+        }
+        frame
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn levels(&self) -> u8 {
+        self.levels
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, level: u8) {
+        assert!(level < self.levels, "Level {} is out of range for {} levels", level, self.levels);
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the frame", x, y);
+        self.cells[y * self.width + x] = level;
+    }
+}
+
+/// A day whose solution evolves a 2D state that can be drawn.
+pub trait Animation: Solution {
+    /// The name of the palette (see `Palette::named`) to draw the frames with by default.
+    const PALETTE: &'static str = "mono";
+
+    /// Every state of the simulation, starting with the initial one.
+    fn frames(input: &Self::Input) -> Vec<Frame>;
+}
+
+/// Parses the input text and draws every frame of a day's simulation, returning them along with
+/// the name of the day's default palette.
+pub type Animator = fn(&str) -> ParseResult<(Vec<Frame>, &'static str)>;
+
+pub fn animate<S: Animation>(input: &str) -> ParseResult<(Vec<Frame>, &'static str)> {
+    let parsed = S::parse(input).map_err(|e| e.locate(input))?;
+    Ok((S::frames(&parsed), S::PALETTE))
+}
+
+pub type Rgb = [u8; 3];
+
+/// A gradient of colours, which levels are spread evenly across, so the lowest level takes the
+/// first colour and the highest the last.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Palette {
+    stops: Vec<Rgb>,
+}
+
+impl Palette {
+    pub const NAMES: [&'static str; 4] = ["mono", "heat", "ocean", "paper"];
+
+    pub fn named(name: &str) -> Option<Palette> {
+        let stops = match name {
+            "mono" => vec![[0, 0, 0], [255, 255, 255]],
+            "heat" => vec![[0, 0, 0], [128, 0, 32], [255, 96, 0], [255, 224, 64], [255, 255, 255]],
+            "ocean" => vec![[0, 24, 64], [0, 128, 160], [160, 255, 224]],
+            "paper" => vec![[255, 255, 240], [32, 32, 32]],
+            _ => return None,
+        };
+        Some(Palette { stops })
+    }
+
+    /// Parses a palette name, or a comma-separated list of hex colours such as "#000000,#ff8000".
+    pub fn parse(spec: &str) -> Result<Palette, String> {
+        if let Some(palette) = Palette::named(spec) {
+            return Ok(palette);
+        }
+        let stops = spec.split(',')
+            .map(|colour| parse_hex_colour(colour.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+        if stops.len() < 2 {
+            return Err(format!("Palette '{}' needs at least 2 colours", spec));
+        }
+        Ok(Palette { stops })
+    }
+
+    /// The colour of `level`, out of `levels` spread across the palette.
+    pub fn colour(&self, level: u8, levels: u8) -> Rgb {
+        let position = level as f64 / (levels - 1).max(1) as f64 * (self.stops.len() - 1) as f64;
+        let below = position.floor() as usize;
+        let above = (below + 1).min(self.stops.len() - 1);
+        let fraction = position - below as f64;
+        [0, 1, 2].map(|channel| {
+            let low = self.stops[below][channel] as f64;
+            let high = self.stops[above][channel] as f64;
+            (low + (high - low) * fraction).round() as u8
+        })
+    }
+}
+
+fn parse_hex_colour(text: &str) -> Result<Rgb, String> {
+    let digits = text.strip_prefix('#').unwrap_or(text);
+    let invalid = || format!("Invalid colour '{}' (expected a palette name or #rrggbb)", text);
+    if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).map_err(|_| invalid());
+    Ok([channel(0)?, channel(2)?, channel(4)?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette_spreads_levels_across_stops() {
+        let palette = Palette::parse("#000000,#ff8000").unwrap();
+        assert_eq!(palette.colour(0, 3), [0, 0, 0]);
+        assert_eq!(palette.colour(1, 3), [128, 64, 0]);
+        assert_eq!(palette.colour(2, 3), [255, 128, 0]);
+    }
+
+    #[test]
+    fn test_palette_parse_errors() {
+        assert!(Palette::parse("#000000").is_err());
+        assert!(Palette::parse("black,white").is_err());
+        assert!(Palette::parse("mono").is_ok());
+    }
+
+    #[test]
+    fn test_frame_from_grid() {
+        let grid = Grid::parse_digits("09\n90").unwrap();
+        let frame = Frame::from_grid(&grid, 2, |&digit| u8::from(digit == 9));
+        assert_eq!((frame.get(0, 0), frame.get(1, 0), frame.get(0, 1)), (0, 1, 1));
+    }
+}
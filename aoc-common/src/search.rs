@@ -0,0 +1,188 @@
+//! Shortest path searches over any graph of states.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A graph to search for the cheapest route from a start state to any goal state.
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+    /// The cost of moving between states, whose `Default` must be zero.
+    type Cost: Copy + Ord + Add<Output = Self::Cost> + Default;
+
+    /// The states reachable in one move from `state`, each with the cost of that move.
+    fn neighbours(&self, state: &Self::State) -> Vec<(Self::State, Self::Cost)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A lower bound on the cost from `state` to the nearest goal, used to guide A*. It must never
+    /// overestimate, nor fall by more than the cost of a move between neighbours, or A* may return
+    /// a path that isn't the cheapest.
+    fn heuristic(&self, _state: &Self::State) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+/// A route found by a search: every state from the start to the goal inclusive, and its total cost.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Path<S, C> {
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+/// Finds the cheapest path from `start` to a goal, ignoring the problem's heuristic.
+pub fn dijkstra<P: SearchProblem>(problem: &P, start: P::State) -> Option<Path<P::State, P::Cost>> {
+    cheapest_first(problem, start, |_| P::Cost::default())
+}
+
+/// Finds the cheapest path from `start` to a goal, exploring first the states the problem's
+/// heuristic estimates are closest.
+pub fn a_star<P: SearchProblem>(problem: &P, start: P::State) -> Option<Path<P::State, P::Cost>> {
+    cheapest_first(problem, start, |state| problem.heuristic(state))
+}
+
+/// Finds the path from `start` to a goal with the fewest moves, ignoring their costs. The path's
+/// cost is its number of moves.
+pub fn bfs<P: SearchProblem>(problem: &P, start: P::State) -> Option<Path<P::State, usize>> {
+    let mut came_from = HashMap::new();
+    came_from.insert(start.clone(), None);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if problem.is_goal(&state) {
+            let states = reconstruct(&came_from, state);
+            return Some(Path { cost: states.len() - 1, states });
+        }
+        for (neighbour, _) in problem.neighbours(&state) {
+            if let Entry::Vacant(entry) = came_from.entry(neighbour.clone()) {
+                entry.insert(Some(state.clone()));
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    None
+}
+
+// A state waiting to be explored, and the state it was reached from. The queue is a max-heap, so
+// the ordering is reversed to pop the lowest estimated total cost first.
+struct Frontier<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+    from: Option<S>,
+}
+impl<S, C: Ord> Ord for Frontier<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+impl<S, C: Ord> PartialOrd for Frontier<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<S, C: Ord> PartialEq for Frontier<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<S, C: Ord> Eq for Frontier<S, C> {}
+
+// States may be queued many times, but are only explored the first time they're popped, which is
+// by the cheapest route. Checking only when popping avoids hashing every neighbour.
+fn cheapest_first<P: SearchProblem>(
+    problem: &P,
+    start: P::State,
+    heuristic: impl Fn(&P::State) -> P::Cost,
+) -> Option<Path<P::State, P::Cost>> {
+    let zero = P::Cost::default();
+    // Each explored state, and the state it was reached from
+    let mut came_from = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    queue.push(Frontier { estimate: heuristic(&start), cost: zero, state: start, from: None });
+
+    while let Some(Frontier { cost, state, from, .. }) = queue.pop() {
+        match came_from.entry(state.clone()) {
+            Entry::Occupied(_) => continue,
+            Entry::Vacant(entry) => { entry.insert(from); },
+        }
+        if problem.is_goal(&state) {
+            return Some(Path { states: reconstruct(&came_from, state), cost });
+        }
+        for (neighbour, move_cost) in problem.neighbours(&state) {
+            let next_cost = cost + move_cost;
+            queue.push(Frontier {
+                estimate: next_cost + heuristic(&neighbour),
+                cost: next_cost,
+                state: neighbour,
+                from: Some(state.clone()),
+            });
+        }
+    }
+    None
+}
+
+fn reconstruct<S: Clone + Eq + Hash>(came_from: &HashMap<S, Option<S>>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(Some(previous)) = came_from.get(states.last().expect("Path cannot be empty")) {
+        states.push(previous.clone());
+    }
+    states.reverse();
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A number line where each step right costs 1, and jumping to double costs 5
+    struct Doubling {
+        goal: u32,
+    }
+    impl SearchProblem for Doubling {
+        type State = u32;
+        type Cost = u32;
+
+        fn neighbours(&self, &n: &u32) -> Vec<(u32, u32)> {
+            vec![(n + 1, 1), (n * 2, 5)].into_iter().filter(|&(m, _)| m <= self.goal).collect()
+        }
+
+        fn is_goal(&self, &n: &u32) -> bool {
+            n == self.goal
+        }
+
+        fn heuristic(&self, &n: &u32) -> u32 {
+            // A doubling covers at least one step, and never costs less than 1
+            u32::from(n < self.goal)
+        }
+    }
+
+    #[test]
+    fn test_dijkstra_finds_cheapest_path() {
+        let path = dijkstra(&Doubling { goal: 30 }, 1).unwrap();
+        assert_eq!(path.states, vec![1, 2, 3, 4, 5, 6, 7, 14, 15, 30]);
+        assert_eq!(path.cost, 17);
+    }
+
+    #[test]
+    fn test_a_star_agrees_with_dijkstra() {
+        let problem = Doubling { goal: 37 };
+        assert_eq!(a_star(&problem, 1).unwrap().cost, dijkstra(&problem, 1).unwrap().cost);
+    }
+
+    #[test]
+    fn test_bfs_finds_fewest_moves() {
+        let path = bfs(&Doubling { goal: 20 }, 1).unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states.first(), Some(&1));
+        assert_eq!(path.states.last(), Some(&20));
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        assert_eq!(dijkstra(&Doubling { goal: 3 }, 5), None);
+        assert_eq!(bfs(&Doubling { goal: 3 }, 5), None);
+    }
+}
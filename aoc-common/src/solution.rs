@@ -0,0 +1,142 @@
+//! The interface each day exposes to the runners.
+
+use std::fmt;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::Instant;
+use crate::answer::{Answer, IntoAnswer, PartResult};
+use crate::parse::{ParseError, ParseResult};
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Unknown part '{}' (expected 1 or 2)", s)),
+        }
+    }
+}
+
+/// A day's puzzle: parsing of the input text, and the two parts solved against the parsed input.
+pub trait Solution {
+    type Input;
+    type Answer1: IntoAnswer;
+    type Answer2: IntoAnswer;
+
+    /// False for days with only one puzzle (i.e. day 25).
+    const HAS_PART_2: bool = true;
+
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// The second "answer" of a day with only one puzzle.
+#[derive(Eq, PartialEq, Debug)]
+pub struct NoPuzzle;
+impl fmt::Display for NoPuzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(no puzzle)")
+    }
+}
+impl Answer for NoPuzzle {}
+
+/// Why a part of a day's puzzle couldn't be solved.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum SolveError {
+    /// The input couldn't be parsed.
+    Parse(ParseError),
+    /// The input parsed, but the part has no answer for it.
+    Part(Part, String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "Invalid input: {}", e),
+            SolveError::Part(part, message) => write!(f, "Could not solve part {}: {}", part, message),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> SolveError {
+        SolveError::Parse(e)
+    }
+}
+
+/// Solves one part of a day's puzzle for the given input text. Returns `None` if the day has no
+/// such part.
+pub type Solver = fn(&str, Part) -> Result<Option<PartResult>, SolveError>;
+
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<Option<PartResult>, SolveError> {
+    if part == Part::Two && !S::HAS_PART_2 {
+        return Ok(None);
+    }
+
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|e| e.locate(input))?;
+    let parse_elapsed = start.elapsed();
+
+    let start = Instant::now();
+    let failed = |message| SolveError::Part(part, message);
+    Ok(Some(match part {
+        Part::One => {
+            let answer = S::part1(&parsed).into_answer().map_err(failed)?;
+            PartResult::new(part, &answer, parse_elapsed, start.elapsed())
+        },
+        Part::Two => {
+            let answer = S::part2(&parsed).into_answer().map_err(failed)?;
+            PartResult::new(part, &answer, parse_elapsed, start.elapsed())
+        },
+    }))
+}
+
+/// Prints the answer to each part, in the same format as the original standalone binaries.
+pub fn print_answers<S: Solution>(input: &str) -> ExitCode {
+    for part in Part::ALL {
+        match solve::<S>(input, part) {
+            Ok(Some(result)) => println!("{}", format_answer(part, &result.display)),
+            Ok(None) => {},
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            },
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+
+/// Formats an answer as "Part N: answer", moving multi-line answers onto their own lines.
+pub fn format_answer(part: Part, answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("Part {}:\n{}", part, answer)
+    } else {
+        format!("Part {}: {}", part, answer)
+    }
+}
@@ -0,0 +1,96 @@
+//! Optional tracing of what solvers are doing, e.g. each step of a simulation.
+//!
+//! Solvers emit events with the `trace!` macro, which does nothing unless an observer has been
+//! installed with `set_observer`, so events can be left in hot loops:
+//!
+//! ```
+//! aoc_common::trace!("fish_by_age", "after day {}: {:?}", 3, [0, 1, 2]);
+//! ```
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// Something a solver did.
+pub struct Event<'a> {
+    /// The module that emitted the event, e.g. "day06".
+    pub source: &'static str,
+    /// What kind of event this is, e.g. "fish_by_age".
+    pub name: &'static str,
+    pub message: fmt::Arguments<'a>,
+}
+
+/// Receives every traced event. Events may arrive from several threads at once.
+pub trait Observer: Send + Sync {
+    fn event(&self, event: &Event);
+}
+
+/// Writes each event to stderr as "[source name] message".
+pub struct StderrObserver;
+impl Observer for StderrObserver {
+    fn event(&self, event: &Event) {
+        eprintln!("[{} {}] {}", event.source, event.name, event.message);
+    }
+}
+
+// Checked before anything else, so tracing costs next to nothing while there's no observer
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static OBSERVER: RwLock<Option<Arc<dyn Observer>>> = RwLock::new(None);
+
+/// Sends all future events to `observer`, or stops tracing if it's `None`.
+pub fn set_observer(observer: Option<Arc<dyn Observer>>) {
+    let enabled = observer.is_some();
+    *OBSERVER.write().expect("Could not lock the trace observer") = observer;
+    ENABLED.store(enabled, Ordering::Release);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+/// Sends `event` to the observer, if there is one. Use the `trace!` macro rather than calling this
+/// directly.
+pub fn emit(event: &Event) {
+    if let Some(observer) = OBSERVER.read().expect("Could not lock the trace observer").as_ref() {
+        observer.event(event);
+    }
+}
+
+/// Emits a trace event with the given name and a `format!`-style message. The message is only
+/// formatted if tracing is enabled.
+#[macro_export]
+macro_rules! trace {
+    ($name:expr, $($arg:tt)+) => {
+        if $crate::trace::is_enabled() {
+            $crate::trace::emit(&$crate::trace::Event {
+                source: module_path!(),
+                name: $name,
+                message: format_args!($($arg)+),
+            });
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use super::*;
+
+    struct Recorder(Mutex<Vec<String>>);
+    impl Observer for Recorder {
+        fn event(&self, event: &Event) {
+            self.0.lock().unwrap().push(format!("{} {}: {}", event.source, event.name, event.message));
+        }
+    }
+
+    #[test]
+    fn test_events_reach_the_observer_only_while_set() {
+        let recorder = Arc::new(Recorder(Mutex::new(vec![])));
+        trace!("step", "before {}", 0);
+        set_observer(Some(recorder.clone()));
+        trace!("step", "during {}", 1);
+        set_observer(None);
+        trace!("step", "after {}", 2);
+        assert_eq!(*recorder.0.lock().unwrap(), vec!["aoc_common::trace::tests step: during 1"]);
+    }
+}
@@ -0,0 +1,478 @@
+//! Random puzzle inputs for property tests. Each `dayNN` strategy generates text that parses, and
+//! that keeps to the same promises as the real puzzle inputs (e.g. day 4's boards all win
+//! eventually), so solvers can be run on it as well as parsers.
+//!
+//! Inputs are generated as text alone, so the days can use these strategies in their own tests.
+
+use proptest::collection::{btree_set, vec};
+use proptest::prelude::*;
+use proptest::sample::{select, subsequence};
+
+/// The generated input for `day`, or `None` if there's no such day.
+pub fn input(day: u8) -> Option<BoxedStrategy<String>> {
+    Some(match day {
+        1 => day01().boxed(),
+        2 => day02().boxed(),
+        3 => day03().boxed(),
+        4 => day04().boxed(),
+        5 => day05().boxed(),
+        6 => day06().boxed(),
+        7 => day07().boxed(),
+        8 => day08().boxed(),
+        9 => day09().boxed(),
+        10 => day10().boxed(),
+        11 => day11().boxed(),
+        12 => day12().boxed(),
+        13 => day13().boxed(),
+        14 => day14().boxed(),
+        15 => day15().boxed(),
+        16 => day16().boxed(),
+        17 => day17().boxed(),
+        18 => day18().boxed(),
+        19 => day19().boxed(),
+        20 => day20().boxed(),
+        21 => day21().boxed(),
+        22 => day22().boxed(),
+        23 => day23().boxed(),
+        24 => day24().boxed(),
+        25 => day25().boxed(),
+        _ => return None,
+    })
+}
+
+// One line per item, with a trailing newline like the real inputs
+fn lines<T: ToString>(items: impl IntoIterator<Item = T>) -> String {
+    items.into_iter().map(|item| item.to_string() + "\n").collect()
+}
+
+fn comma_separated<T: ToString>(items: &[T]) -> String {
+    items.iter().map(T::to_string).collect::<Vec<_>>().join(",") + "\n"
+}
+
+// A rectangle of characters, chosen from `chars`
+fn char_grid(chars: &'static [char], max_width: usize, max_height: usize) -> impl Strategy<Value = String> {
+    (1..=max_width, 1..=max_height)
+        .prop_flat_map(move |(width, height)| vec(vec(select(chars), width), height))
+        .prop_map(|rows| lines(rows.into_iter().map(String::from_iter)))
+}
+
+/// Sonar depths.
+pub fn day01() -> impl Strategy<Value = String> {
+    vec(100u32..10000, 1..200).prop_map(lines)
+}
+
+/// Submarine commands, which never take it above the surface.
+pub fn day02() -> impl Strategy<Value = String> {
+    vec((0..3, 1u32..10), 1..100).prop_map(|commands| {
+        let mut depth = 0;
+        lines(commands.into_iter().map(|(command, distance)| match command {
+            0 => format!("forward {}", distance),
+            1 if depth >= distance => {
+                depth -= distance;
+                format!("up {}", distance)
+            },
+            _ => {
+                depth += distance;
+                format!("down {}", distance)
+            },
+        }))
+    })
+}
+
+/// A diagnostic report of distinct numbers, all between 1 and 64 bits wide.
+pub fn day03() -> impl Strategy<Value = String> {
+    (1u32..=64).prop_flat_map(|width| {
+        // Narrow reports can't hold many distinct numbers
+        let most = if width < 7 { 1 << width } else { 99 };
+        btree_set(any::<u64>().prop_map(move |n| n >> (64 - width)), 2..=most)
+            .prop_map(|numbers| numbers.into_iter().collect::<Vec<_>>())
+            .prop_shuffle()
+            .prop_map(move |numbers| lines(numbers.into_iter().map(|n| format!("{:0width$b}", n, width = width as usize))))
+    })
+}
+
+/// Every number from 0 to 99 drawn in a random order, and boards of distinct numbers from them.
+pub fn day04() -> impl Strategy<Value = String> {
+    let draws = Just((0..100).collect::<Vec<u32>>()).prop_shuffle();
+    let board = subsequence((0..100).collect::<Vec<u32>>(), 25).prop_shuffle();
+    (draws, vec(board, 1..8)).prop_map(|(draws, boards)| {
+        let boards = boards.into_iter().map(|board| {
+            lines(board.chunks(5).map(|row| row.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>().join(" ")))
+        });
+        std::iter::once(comma_separated(&draws)).chain(boards).collect::<Vec<_>>().join("\n")
+    })
+}
+
+/// Horizontal, vertical and 45 degree diagonal lines of vents, which may have negative coordinates.
+pub fn day05() -> impl Strategy<Value = String> {
+    let direction = select(vec![(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)]);
+    vec((-250i32..250, -250i32..250, direction, 1i32..50), 1..100).prop_map(|vents| {
+        lines(vents.into_iter().map(|(x, y, (dx, dy), length)| {
+            format!("{},{} -> {},{}", x, y, x + dx * length, y + dy * length)
+        }))
+    })
+}
+
+/// Lanternfish timers.
+pub fn day06() -> impl Strategy<Value = String> {
+    vec(0u8..=8, 1..300).prop_map(|timers| comma_separated(&timers))
+}
+
+/// Crab positions.
+pub fn day07() -> impl Strategy<Value = String> {
+    vec(0u32..500, 1..100).prop_map(|positions| comma_separated(&positions))
+}
+
+/// Seven segment displays, each wired up with a random permutation of segments.
+pub fn day08() -> impl Strategy<Value = String> {
+    const DIGITS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+    let wiring = Just("abcdefg".chars().collect::<Vec<_>>()).prop_shuffle();
+    let patterns = Just((0..10).collect::<Vec<usize>>()).prop_shuffle();
+    let display = (wiring, patterns, vec(0..10usize, 4)).prop_map(|(wiring, patterns, output)| {
+        // Segments come out in a scrambled order, as the wiring maps them to different letters
+        let wire = |digit: usize| DIGITS[digit].bytes().map(|s| wiring[(s - b'a') as usize]).collect::<String>();
+        let patterns = patterns.into_iter().map(&wire).collect::<Vec<_>>();
+        let output = output.into_iter().map(&wire).collect::<Vec<_>>();
+        format!("{} | {}", patterns.join(" "), output.join(" "))
+    });
+    vec(display, 1..50).prop_map(lines)
+}
+
+/// A heightmap where each height is the distance to the nearest of some low points, so that every
+/// basin has a single low point.
+pub fn day09() -> impl Strategy<Value = String> {
+    (1..30usize, 1..30usize)
+        .prop_flat_map(|(width, height)| (Just((width, height)), vec((0..width, 0..height), 1..10)))
+        .prop_map(|((width, height), points)| {
+            // Neighbouring low points would tie with each other, so neither would be low
+            let mut low_points: Vec<(usize, usize)> = vec![];
+            for (x, y) in points {
+                if low_points.iter().all(|&(lx, ly)| lx.abs_diff(x) + ly.abs_diff(y) > 1) {
+                    low_points.push((x, y));
+                }
+            }
+            lines((0..height).map(|y| {
+                (0..width).map(|x| {
+                    let distance = low_points.iter().map(|&(lx, ly)| lx.abs_diff(x) + ly.abs_diff(y)).min().unwrap_or(9);
+                    char::from_digit(distance.min(9) as u32, 10).expect("Height is a digit")
+                }).collect::<String>()
+            }))
+        })
+}
+
+// Brackets that are opened and closed at random, leaving at least one open. A corrupted line
+// ends with the wrong closing bracket.
+fn bracket_line(corrupt: bool) -> impl Strategy<Value = String> {
+    const OPEN: [char; 4] = ['(', '[', '{', '<'];
+    const CLOSE: [char; 4] = [')', ']', '}', '>'];
+    vec((any::<bool>(), 0..4usize), 1..60).prop_map(move |steps| {
+        let mut line = String::new();
+        let mut open = vec![];
+        for (opens, bracket) in steps {
+            match open.last() {
+                Some(&last) if !opens => {
+                    line.push(CLOSE[last]);
+                    open.pop();
+                },
+                _ => {
+                    line.push(OPEN[bracket]);
+                    open.push(bracket);
+                },
+            }
+        }
+        match open.last() {
+            Some(&last) if corrupt => line.push(CLOSE[(last + 1) % 4]),
+            Some(_) => (),
+            None => line.push('('),
+        }
+        line
+    })
+}
+
+/// Lines of brackets that are each either corrupted or incomplete, and never complete. The first
+/// line is always incomplete.
+pub fn day10() -> impl Strategy<Value = String> {
+    (bracket_line(false), vec(any::<bool>().prop_flat_map(bracket_line), 0..50))
+        .prop_map(|(first, rest)| lines(std::iter::once(first).chain(rest)))
+}
+
+/// Octopus energy levels.
+pub fn day11() -> impl Strategy<Value = String> {
+    char_grid(&['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'], 12, 12)
+}
+
+/// A cave system where no two big caves are connected, so there are only so many paths.
+pub fn day12() -> impl Strategy<Value = String> {
+    const CAVES: [&str; 9] = ["start", "end", "ab", "cd", "ef", "gh", "ij", "XY", "Z"];
+    let is_big = |cave: usize| CAVES[cave].chars().all(|c| c.is_ascii_uppercase());
+    let connection = (0..CAVES.len(), 0..CAVES.len())
+        .prop_filter("Caves must differ, and not both be big", move |&(a, b)| a != b && !(is_big(a) && is_big(b)));
+    (2..CAVES.len(), 2..CAVES.len(), btree_set(connection, 0..12)).prop_map(|(first, last, connections)| {
+        let connections = [(0, first), (last, 1)].into_iter().chain(connections);
+        lines(connections.map(|(a, b)| format!("{}-{}", CAVES[a], CAVES[b])))
+    })
+}
+
+/// Dots on transparent paper and folds of it in half, where no dot is ever on a fold line.
+pub fn day13() -> impl Strategy<Value = String> {
+    (1..12u32, 1..8u32, vec(any::<bool>(), 1..5))
+        .prop_flat_map(|(width, height, folds_x)| {
+            let dot = (0..width, 0..height, vec(any::<bool>(), folds_x.len()));
+            (Just(width), Just(height), Just(folds_x), vec(dot, 1..40))
+        })
+        .prop_map(|(mut width, mut height, folds_x, dots)| {
+            // Work out where each fold was from the size of the paper once folded, by unfolding it
+            let mut folds = vec![];
+            for &x in folds_x.iter().rev() {
+                if x {
+                    folds.push(('x', width));
+                    width = 2 * width + 1;
+                } else {
+                    folds.push(('y', height));
+                    height = 2 * height + 1;
+                }
+            }
+            // Unfolding each dot from the folded paper, it may be on either side of each fold
+            let dots = dots.into_iter().map(|(mut x, mut y, flips)| {
+                for (&(axis, line), flip) in folds.iter().zip(flips) {
+                    match axis {
+                        'x' if flip => x = 2 * line - x,
+                        'y' if flip => y = 2 * line - y,
+                        _ => (),
+                    }
+                }
+                format!("{},{}", x, y)
+            });
+            let folds = folds.iter().rev().map(|(axis, line)| format!("fold along {}={}", axis, line));
+            format!("{}\n{}", lines(dots), lines(folds))
+        })
+}
+
+/// A polymer template and an insertion rule for every pair of its elements.
+pub fn day14() -> impl Strategy<Value = String> {
+    const ELEMENTS: [char; 4] = ['B', 'C', 'H', 'N'];
+    (vec(select(ELEMENTS.to_vec()), 2..20), vec(select(ELEMENTS.to_vec()), 16)).prop_map(|(template, inserts)| {
+        let rules = inserts.into_iter().enumerate()
+            .map(|(i, insert)| format!("{}{} -> {}", ELEMENTS[i / 4], ELEMENTS[i % 4], insert));
+        format!("{}\n\n{}", String::from_iter(template), lines(rules))
+    })
+}
+
+/// Cave risk levels.
+pub fn day15() -> impl Strategy<Value = String> {
+    char_grid(&['1', '2', '3', '4', '5', '6', '7', '8', '9'], 20, 20)
+}
+
+#[derive(Clone, Debug)]
+enum Packet {
+    Literal(u8, u64),
+    Operator { version: u8, type_id: u8, count_length: bool, packets: Vec<Packet> },
+}
+
+impl Packet {
+    fn encode(&self, bits: &mut Vec<bool>) {
+        fn push(bits: &mut Vec<bool>, value: u64, width: usize) {
+            bits.extend((0..width).rev().map(|i| value >> i & 1 == 1));
+        }
+        match self {
+            Packet::Literal(version, value) => {
+                push(bits, *version as u64, 3);
+                push(bits, 4, 3);
+                let groups = (64 - value.leading_zeros() as usize).div_ceil(4).max(1);
+                for group in (0..groups).rev() {
+                    push(bits, u64::from(group > 0), 1);
+                    push(bits, value >> (group * 4) & 0xf, 4);
+                }
+            },
+            Packet::Operator { version, type_id, count_length, packets } => {
+                push(bits, *version as u64, 3);
+                push(bits, *type_id as u64, 3);
+                let mut inner = vec![];
+                packets.iter().for_each(|packet| packet.encode(&mut inner));
+                if *count_length {
+                    push(bits, 1, 1);
+                    push(bits, packets.len() as u64, 11);
+                } else {
+                    push(bits, 0, 1);
+                    push(bits, inner.len() as u64, 15);
+                }
+                bits.extend(inner);
+            },
+        }
+    }
+}
+
+/// A transmission of nested packets, padded with zeros to a whole number of bytes.
+pub fn day16() -> impl Strategy<Value = String> {
+    // Values are kept small enough that no product of them can overflow
+    let literal = (0u8..8, 0u64..16).prop_map(|(version, value)| Packet::Literal(version, value));
+    let packet = literal.prop_recursive(3, 27, 3, |inner| {
+        let operator = |types: Vec<u8>, packets| {
+            (0u8..8, select(types), any::<bool>(), packets).prop_map(|(version, type_id, count_length, packets)| {
+                Packet::Operator { version, type_id, count_length, packets }
+            })
+        };
+        prop_oneof![
+            operator(vec![0, 1, 2, 3], vec(inner.clone(), 1..=3)),
+            operator(vec![5, 6, 7], vec(inner, 2)),
+        ]
+    });
+    packet.prop_map(|packet| {
+        let mut bits = vec![];
+        packet.encode(&mut bits);
+        bits.resize(bits.len().div_ceil(8) * 8, false);
+        let hex = bits.chunks(4)
+            .map(|nibble| nibble.iter().fold(0, |acc, &bit| acc << 1 | u32::from(bit)))
+            .map(|nibble| char::from_digit(nibble, 16).expect("Nibble is a hex digit").to_ascii_uppercase())
+            .collect::<String>();
+        hex + "\n"
+    })
+}
+
+/// A target area to the right of and below the launcher.
+pub fn day17() -> impl Strategy<Value = String> {
+    (1..100i32, 0..30i32, -100..0i32, 0..30i32).prop_map(|(left, width, top, height)| {
+        format!("target area: x={}..{}, y={}..{}\n", left, left + width, top - height, top)
+    })
+}
+
+/// A snail number whose pairs are nested at most four deep, with literals from 0 to 9, like the
+/// result of reducing one.
+pub fn snail_number() -> impl Strategy<Value = String> {
+    let element = (0u32..10).prop_map(|n| n.to_string())
+        .prop_recursive(3, 16, 2, |inner| (inner.clone(), inner).prop_map(|(l, r)| format!("[{},{}]", l, r)));
+    (element.clone(), element).prop_map(|(l, r)| format!("[{},{}]", l, r))
+}
+
+/// A list of snail numbers.
+pub fn day18() -> impl Strategy<Value = String> {
+    vec(snail_number(), 2..10).prop_map(lines)
+}
+
+// All 24 rotations: permutations of the axes and their signs that don't mirror
+fn rotations() -> Vec<([usize; 3], [i32; 3])> {
+    let permutations = [([0, 1, 2], 1), ([1, 2, 0], 1), ([2, 0, 1], 1), ([0, 2, 1], -1), ([2, 1, 0], -1), ([1, 0, 2], -1)];
+    let mut rotations = vec![];
+    for (axes, parity) in permutations {
+        for signs in 0..8 {
+            let signs = [0, 1, 2].map(|i| if signs >> i & 1 == 1 { -1 } else { 1 });
+            if signs.iter().product::<i32>() == parity {
+                rotations.push((axes, signs));
+            }
+        }
+    }
+    rotations
+}
+
+/// Scanners, each turned and placed at random, where each scanner sees 12 of the same beacons as
+/// the one before it.
+pub fn day19() -> impl Strategy<Value = String> {
+    let beacon = [-700..700i32, -700..700i32, -700..700i32];
+    let scanner = ([-300..300i32, -300..300i32, -300..300i32], 0..24usize);
+    (1..5usize)
+        .prop_flat_map(move |scanners| {
+            (btree_set(beacon.clone(), 12 * (scanners + 1)), vec(scanner.clone(), scanners))
+        })
+        .prop_map(|(beacons, scanners)| {
+            let beacons = beacons.into_iter().collect::<Vec<_>>();
+            let rotations = rotations();
+            scanners.into_iter().enumerate().map(|(i, (offset, rotation))| {
+                let (axes, signs) = rotations[rotation];
+                let seen = beacons[12 * i..12 * (i + 2)].iter().map(|beacon| {
+                    let [x, y, z] = [0, 1, 2].map(|axis| (beacon[axes[axis]] - offset[axes[axis]]) * signs[axis]);
+                    format!("{},{},{}", x, y, z)
+                });
+                format!("--- scanner {} ---\n{}", i, lines(seen))
+            }).collect::<Vec<_>>().join("\n")
+        })
+}
+
+/// An image enhancement algorithm and an image. If the algorithm lights up an empty area, it also
+/// darkens a fully lit one, so that the lit pixels can be counted after every other step.
+pub fn day20() -> impl Strategy<Value = String> {
+    (vec(any::<bool>(), 512), char_grid(&['#', '.'], 15, 15)).prop_map(|(mut algorithm, image)| {
+        if algorithm[0] {
+            algorithm[511] = false;
+        }
+        let algorithm = algorithm.into_iter().map(|lit| if lit { '#' } else { '.' }).collect::<String>();
+        format!("{}\n\n{}", algorithm, image)
+    })
+}
+
+/// The players' starting positions.
+pub fn day21() -> impl Strategy<Value = String> {
+    (1..=10u32, 1..=10u32).prop_map(|(p1, p2)| {
+        format!("Player 1 starting position: {}\nPlayer 2 starting position: {}\n", p1, p2)
+    })
+}
+
+fn cuboid_step(on: bool, [x, y, z]: [(i32, i32); 3]) -> String {
+    format!("{} x={}..{},y={}..{},z={}..{}", if on { "on" } else { "off" }, x.0, x.1, y.0, y.1, z.0, z.1)
+}
+
+// A range somewhere in -size..size, at most `length` long
+fn range(size: i32, length: i32) -> impl Strategy<Value = (i32, i32)> {
+    (-size..size, 0..length).prop_map(|(start, length)| (start, start + length))
+}
+
+/// Reboot steps, mostly within the initialisation area but some further out.
+pub fn day22() -> impl Strategy<Value = String> {
+    let near = [range(60, 40), range(60, 40), range(60, 40)];
+    let far = [range(100_000, 50_000), range(100_000, 50_000), range(100_000, 50_000)];
+    let cuboid = prop_oneof![4 => near, 1 => far];
+    vec((any::<bool>(), cuboid), 1..20).prop_map(|steps| lines(steps.into_iter().map(|(on, cuboid)| cuboid_step(on, cuboid))))
+}
+
+/// Reboot steps whose cuboids don't overlap, as lines so they can be reordered. Each cuboid is in a
+/// separate slab along the x axis.
+pub fn day22_disjoint_steps() -> impl Strategy<Value = Vec<String>> {
+    vec((any::<bool>(), 0..20i32, range(60, 40), range(60, 40)), 1..20).prop_map(|steps| {
+        steps.into_iter().enumerate()
+            .map(|(i, (on, width, y, z))| {
+                let x = -60 + 21 * i as i32;
+                cuboid_step(on, [(x, x + width), y, z])
+            })
+            .collect()
+    })
+}
+
+/// A burrow with the amphipods shuffled between the rooms.
+pub fn day23() -> impl Strategy<Value = String> {
+    Just("AABBCCDD".chars().collect::<Vec<_>>()).prop_shuffle().prop_map(|a| {
+        format!(
+            "#############\n#...........#\n###{}#{}#{}#{}###\n  #{}#{}#{}#{}#\n  #########\n",
+            a[0], a[1], a[2], a[3], a[4], a[5], a[6], a[7],
+        )
+    })
+}
+
+/// A MONAD program of 14 sections, where the sections that divide z by 26 pair off with the ones
+/// before them like brackets, and each pair of digits can be made to match.
+pub fn day24() -> impl Strategy<Value = String> {
+    (vec(any::<bool>(), 14), vec(10..=16i32, 14), vec(0..=16i32, 14), vec(-8..=8i32, 14)).prop_map(|(opens, x_adds, y_adds, differences)| {
+        let mut sections = vec![];
+        let mut pushed = vec![];
+        for i in 0..14 {
+            // Only push if there'll still be enough sections left to pop everything pushed
+            if pushed.is_empty() || (opens[i] && pushed.len() + 2 <= 14 - i) {
+                pushed.push(y_adds[i]);
+                sections.push((1, x_adds[i], y_adds[i]));
+            } else {
+                let y_add = pushed.pop().expect("A section was pushed");
+                sections.push((26, differences[i] - y_add, y_adds[i]));
+            }
+        }
+        sections.into_iter().map(|(div, x_add, y_add)| {
+            format!(
+                "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\n\
+                 add y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y\n",
+                div, x_add, y_add,
+            )
+        }).collect()
+    })
+}
+
+/// Herds of sea cucumbers.
+pub fn day25() -> impl Strategy<Value = String> {
+    char_grid(&['>', 'v', '.'], 20, 20)
+}
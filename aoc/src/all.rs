@@ -0,0 +1,249 @@
+//! The `all` command: solves every day at once on a pool of worker threads, printing each day's
+//! answers as soon as it finishes, then a summary table.
+
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+use std::panic;
+use std::process::ExitCode;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use aoc_common::answer::PartResult;
+use aoc_common::bench::format_duration;
+use aoc_common::solution::SolveError;
+use aoc_common::{input, Part};
+use crate::args::{AllArgs, Format};
+use crate::{days, output};
+
+/// How a day's run ended.
+pub enum Outcome {
+    Solved(Vec<PartResult>),
+    /// The input couldn't be read or parsed, a part had no answer for it, or the solver panicked.
+    Failed(String),
+    TimedOut,
+}
+
+pub struct DayReport {
+    pub day: u8,
+    pub outcome: Outcome,
+    /// The wall time from the day being picked up by a worker to it finishing or timing out.
+    pub elapsed: Duration,
+}
+
+pub fn run(args: AllArgs) -> ExitCode {
+    let all_days = (1..=days::NUM_DAYS).collect::<Vec<_>>();
+    let start = Instant::now();
+    let mut reports = vec![];
+    schedule(&all_days, args.jobs, args.timeout, solve_day, |report| {
+        print_report(args.format, &report);
+        reports.push(report);
+    });
+    let wall_time = start.elapsed();
+
+    reports.sort_by_key(|report| report.day);
+    match args.format {
+        Format::Text => print_table(&reports, wall_time, args.jobs),
+        Format::Json => print_json_summary(&reports, wall_time),
+    }
+    if reports.iter().all(|report| matches!(report.outcome, Outcome::Solved(_))) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+// Solves both parts of the day against its own input
+fn solve_day(day: u8) -> Outcome {
+    let input_path = days::default_input_path(day);
+    let input = match input::read(&input_path.to_string_lossy()) {
+        Ok(input) => input,
+        Err(e) => return Outcome::Failed(format!("Could not read input {}: {}", input_path.display(), e)),
+    };
+    let solver = days::solver(day).expect("Every day has a solver");
+    let mut results = vec![];
+    for part in Part::ALL {
+        match solver(&input, part) {
+            Ok(result) => results.extend(result),
+            Err(SolveError::Parse(e)) => return Outcome::Failed(format!("Invalid input in {}: {}", input_path.display(), e)),
+            Err(e) => return Outcome::Failed(e.to_string()),
+        }
+    }
+    Outcome::Solved(results)
+}
+
+enum Message {
+    Started(u8, Instant),
+    Finished(DayReport),
+}
+
+type Job = fn(u8) -> Outcome;
+
+/// Runs `job` for each day on `workers` threads, passing each day's report to `on_report` as it
+/// arrives. A day still running after `timeout` is reported as timed out, and as its thread can't
+/// be stopped, a new worker is started in its place.
+fn schedule(days: &[u8], workers: usize, timeout: Duration, job: Job, mut on_report: impl FnMut(DayReport)) {
+    let queue = Arc::new(Mutex::new(days.iter().copied().collect::<VecDeque<_>>()));
+    let (sender, receiver) = mpsc::channel();
+    for _ in 0..workers.min(days.len()) {
+        spawn_worker(&queue, &sender, job);
+    }
+
+    // When each day that's still running was started
+    let mut running: HashMap<u8, Instant> = HashMap::new();
+    let mut remaining = days.len();
+    while remaining > 0 {
+        let message = match running.values().map(|&started| started + timeout).min() {
+            Some(deadline) => receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match message {
+            Ok(Message::Started(day, started)) => {
+                running.insert(day, started);
+            },
+            Ok(Message::Finished(report)) => {
+                // Days that timed out have already been reported
+                if running.remove(&report.day).is_some() {
+                    remaining -= 1;
+                    on_report(report);
+                }
+            },
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                let timed_out = running.iter()
+                    .filter(|&(_, &started)| now >= started + timeout)
+                    .map(|(&day, _)| day)
+                    .collect::<Vec<_>>();
+                for day in timed_out {
+                    running.remove(&day);
+                    remaining -= 1;
+                    on_report(DayReport { day, outcome: Outcome::TimedOut, elapsed: timeout });
+                    spawn_worker(&queue, &sender, job);
+                }
+            },
+            Err(RecvTimeoutError::Disconnected) => unreachable!("The scheduler keeps a sender open"),
+        }
+    }
+}
+
+// Takes days from the queue until it's empty, or until the scheduler has stopped listening
+fn spawn_worker(queue: &Arc<Mutex<VecDeque<u8>>>, sender: &Sender<Message>, job: Job) {
+    let (queue, sender) = (Arc::clone(queue), sender.clone());
+    thread::spawn(move || loop {
+        let Some(day) = queue.lock().expect("Could not lock the queue of days").pop_front() else {
+            break;
+        };
+        let started = Instant::now();
+        if sender.send(Message::Started(day, started)).is_err() {
+            break;
+        }
+        let outcome = panic::catch_unwind(|| job(day))
+            .unwrap_or_else(|payload| Outcome::Failed(format!("Panicked: {}", panic_message(payload))));
+        if sender.send(Message::Finished(DayReport { day, outcome, elapsed: started.elapsed() })).is_err() {
+            break;
+        }
+    });
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or("(no message)".to_string(), |s| s.to_string()),
+    }
+}
+
+fn print_report(format: Format, report: &DayReport) {
+    match &report.outcome {
+        Outcome::Solved(results) => results.iter().for_each(|result| output::print_result(format, report.day, result)),
+        Outcome::Failed(message) => output::print_failure(format, report.day, message),
+        Outcome::TimedOut => {
+            output::print_failure(format, report.day, &format!("Timed out after {}", format_duration(report.elapsed)));
+        },
+    }
+}
+
+// The answer, unless it's too long to fit in the table (e.g. day 13's letters)
+fn table_answer(results: &[PartResult], part: Part) -> String {
+    match results.iter().find(|result| result.part == part) {
+        Some(result) if result.value.contains('\n') => "(see above)".to_string(),
+        Some(result) => result.value.clone(),
+        None => "-".to_string(),
+    }
+}
+
+fn print_table(reports: &[DayReport], wall_time: Duration, workers: usize) {
+    println!();
+    println!("Day  {:<18} {:<18} {:>8}", "Part 1", "Part 2", "Time");
+    for report in reports {
+        let (part1, part2) = match &report.outcome {
+            Outcome::Solved(results) => (table_answer(results, Part::One), table_answer(results, Part::Two)),
+            Outcome::Failed(_) => ("failed".to_string(), String::new()),
+            Outcome::TimedOut => ("timed out".to_string(), String::new()),
+        };
+        println!("{:>3}  {:<18} {:<18} {:>8}", report.day, part1, part2, format_duration(report.elapsed));
+    }
+
+    let solving_time = reports.iter().map(|report| report.elapsed).sum::<Duration>();
+    let solved = reports.iter().filter(|report| matches!(report.outcome, Outcome::Solved(_))).count();
+    println!();
+    println!("Solved {} of {} days in {} ({} of solving across {} workers)",
+             solved,
+             reports.len(),
+             format_duration(wall_time),
+             format_duration(solving_time),
+             workers);
+}
+
+#[derive(Serialize)]
+struct JsonSummary {
+    solved: usize,
+    failed: usize,
+    timed_out: usize,
+    wall_ns: u128,
+    solving_ns: u128,
+}
+
+fn print_json_summary(reports: &[DayReport], wall_time: Duration) {
+    let count = |matches: fn(&Outcome) -> bool| reports.iter().filter(|report| matches(&report.outcome)).count();
+    let summary = JsonSummary {
+        solved: count(|outcome| matches!(outcome, Outcome::Solved(_))),
+        failed: count(|outcome| matches!(outcome, Outcome::Failed(_))),
+        timed_out: count(|outcome| matches!(outcome, Outcome::TimedOut)),
+        wall_ns: wall_time.as_nanos(),
+        solving_ns: reports.iter().map(|report| report.elapsed).sum::<Duration>().as_nanos(),
+    };
+    println!("{}", serde_json::to_string(&summary).expect("Could not serialise summary"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slow_job(day: u8) -> Outcome {
+        match day {
+            2 => thread::sleep(Duration::from_secs(5)),
+            3 => panic!("Day 3 exploded"),
+            _ => thread::sleep(Duration::from_millis(10)),
+        }
+        Outcome::Solved(vec![])
+    }
+
+    #[test]
+    fn test_schedule_reports_every_day_despite_timeouts_and_panics() {
+        let mut reports = vec![];
+        schedule(&[1, 2, 3, 4, 5], 2, Duration::from_millis(200), slow_job, |report| reports.push(report));
+
+        let mut outcomes = reports.iter().map(|report| (report.day, &report.outcome)).collect::<Vec<_>>();
+        outcomes.sort_by_key(|&(day, _)| day);
+        assert!(matches!(outcomes[..], [
+            (1, Outcome::Solved(_)),
+            (2, Outcome::TimedOut),
+            (3, Outcome::Failed(_)),
+            (4, Outcome::Solved(_)),
+            (5, Outcome::Solved(_)),
+        ]));
+        // The timed out day was the last to be reported, as the others didn't wait for it
+        assert_eq!(reports.last().map(|report| report.day), Some(2));
+    }
+}
@@ -0,0 +1,333 @@
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+use aoc_common::render::Palette;
+use aoc_common::Part;
+use crate::days::{self, NUM_DAYS};
+use crate::render::ImageFormat;
+
+pub const USAGE: &str = "\
+Usage:
+  aoc run --day <1-25> [--part <1|2>] [--input <path|->] [--format <text|json>] [--trace]
+          [--mode <mode>]
+  aoc all [--jobs <n>] [--timeout <seconds>] [--format <text|json>]
+  aoc bench [--day <1-25>] [--iterations <n>] [--input <path|->] [--output <path>]
+  aoc render --day <5|11|13|20|25> [--input <path|->] [--format <gif|png|ppm>] [--palette <palette>]
+             [--scale <n>] [--output <path>] [--mode <mode>]
+
+Options:
+  --day          The day to run (default for bench: every day)
+  --part         The part to run (default: both)
+  --input        The input file, or - to read from stdin (default: dayNN/input)
+  --format       Print answers as text, or as one JSON object per line (default: text)
+  --trace        Print the solvers' trace events (e.g. each simulation step) to stderr
+  --mode         For days that can be solved more than one way, which to use: for day 5, puzzle
+                 or general, which allows lines at any angle (default: puzzle)
+  --jobs         For all, how many days to solve at once (default: one per CPU)
+  --timeout      For all, how many seconds each day may take before it's given up on (default: 60)
+  --iterations   How many times to time each phase (default: 10)
+  --output       For bench, the CSV file results are appended to (default: bench-results.csv).
+                 For render, the GIF file (default: dayNN.gif) or the directory for PNG or PPM
+                 frames (default: dayNN-frames)
+  --format       For render, whether to write an animated GIF or a file per frame (default: gif)
+  --palette      For render, a palette (mono, heat, ocean or paper) or a comma-separated list of
+                 colours such as #000000,#ff8000 (default: chosen by the day)
+  --scale        For render, the width in pixels of each cell (default: fits about 800 pixels)";
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+pub const DEFAULT_TIMEOUT_SECS: u64 = 60;
+pub const DEFAULT_BENCH_OUTPUT: &str = "bench-results.csv";
+
+pub enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    All(AllArgs),
+    Render(RenderArgs),
+    Help,
+}
+
+pub struct RunArgs {
+    pub day: u8,
+    pub part: Option<Part>,
+    pub input: Option<String>,
+    pub format: Format,
+    pub trace: bool,
+    pub mode: Option<String>,
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Format {
+    Text,
+    Json,
+}
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format '{}' (expected text or json)", s)),
+        }
+    }
+}
+
+pub struct BenchArgs {
+    pub days: Vec<u8>,
+    pub iterations: usize,
+    pub input: Option<String>,
+    pub output: String,
+}
+
+pub struct AllArgs {
+    pub jobs: usize,
+    pub timeout: Duration,
+    pub format: Format,
+}
+
+pub struct RenderArgs {
+    pub day: u8,
+    pub input: Option<String>,
+    pub format: ImageFormat,
+    pub palette: Option<Palette>,
+    pub scale: Option<usize>,
+    pub output: Option<String>,
+    pub mode: Option<String>,
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run") => parse_run(args).map(Command::Run),
+        Some("bench") => parse_bench(args).map(Command::Bench),
+        Some("all") => parse_all(args).map(Command::All),
+        Some("render") => parse_render(args).map(Command::Render),
+        Some("help") | Some("--help") | Some("-h") | None => Ok(Command::Help),
+        Some(other) => Err(format!("Unknown command '{}'", other)),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut format = Format::Text;
+    let mut trace = false;
+    let mut mode = None;
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("Missing value for {}", flag));
+        match flag.as_str() {
+            "--day" => day = Some(parse_day(&value()?)?),
+            "--part" => part = Some(value()?.parse()?),
+            "--input" => input = Some(value()?),
+            "--format" => format = value()?.parse()?,
+            "--trace" => trace = true,
+            "--mode" => mode = Some(value()?),
+            _ => return Err(format!("Unknown option '{}'", flag)),
+        }
+    }
+    let day = day.ok_or("Missing required option --day")?;
+    check_mode(day, mode.as_deref())?;
+    Ok(RunArgs { day, part, input, format, trace, mode })
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+    let mut day = None;
+    let mut iterations = DEFAULT_ITERATIONS;
+    let mut input = None;
+    let mut output = DEFAULT_BENCH_OUTPUT.to_string();
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("Missing value for {}", flag));
+        match flag.as_str() {
+            "--day" => day = Some(parse_day(&value()?)?),
+            "--iterations" => iterations = parse_positive(&value()?, "iterations")?,
+            "--input" => input = Some(value()?),
+            "--output" => output = value()?,
+            _ => return Err(format!("Unknown option '{}'", flag)),
+        }
+    }
+    let days = match day {
+        Some(day) => vec![day],
+        None if input.is_some() => return Err("--input can only be used with --day".to_string()),
+        None => (1..=NUM_DAYS).collect(),
+    };
+    Ok(BenchArgs { days, iterations, input, output })
+}
+
+fn parse_all(mut args: impl Iterator<Item = String>) -> Result<AllArgs, String> {
+    let mut jobs = None;
+    let mut timeout = Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+    let mut format = Format::Text;
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("Missing value for {}", flag));
+        match flag.as_str() {
+            "--jobs" => jobs = Some(parse_positive(&value()?, "jobs")?),
+            "--timeout" => timeout = Duration::from_secs(parse_positive(&value()?, "timeout")? as u64),
+            "--format" => format = value()?.parse()?,
+            _ => return Err(format!("Unknown option '{}'", flag)),
+        }
+    }
+    let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, NonZeroUsize::get));
+    Ok(AllArgs { jobs, timeout, format })
+}
+
+fn parse_render(mut args: impl Iterator<Item = String>) -> Result<RenderArgs, String> {
+    let mut day = None;
+    let mut input = None;
+    let mut format = ImageFormat::Gif;
+    let mut palette = None;
+    let mut scale = None;
+    let mut output = None;
+    let mut mode = None;
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("Missing value for {}", flag));
+        match flag.as_str() {
+            "--day" => day = Some(parse_day(&value()?)?),
+            "--input" => input = Some(value()?),
+            "--format" => format = value()?.parse()?,
+            "--palette" => palette = Some(Palette::parse(&value()?)?),
+            "--scale" => scale = Some(parse_positive(&value()?, "scale")?),
+            "--output" => output = Some(value()?),
+            "--mode" => mode = Some(value()?),
+            _ => return Err(format!("Unknown option '{}'", flag)),
+        }
+    }
+    let day = day.ok_or("Missing required option --day")?;
+    check_mode(day, mode.as_deref())?;
+    if days::animator(day, mode.as_deref()).is_none() {
+        return Err(format!("Day {} has no simulation to render (try 5, 11, 13, 20 or 25)", day));
+    }
+    Ok(RenderArgs { day, input, format, palette, scale, output, mode })
+}
+
+fn check_mode(day: u8, mode: Option<&str>) -> Result<(), String> {
+    match (mode, days::modes(day)) {
+        (Some(_), []) => Err(format!("Day {} can only be solved one way, so has no modes", day)),
+        (Some(mode), modes) if !modes.contains(&mode) => {
+            Err(format!("Unknown mode '{}' for day {} (expected {})", mode, day, modes.join(" or ")))
+        },
+        _ => Ok(()),
+    }
+}
+
+fn parse_positive(value: &str, what: &str) -> Result<usize, String> {
+    match value.parse() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("Invalid {} '{}' (expected a positive number)", what, value)),
+    }
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse() {
+        Ok(day) if (1..=NUM_DAYS).contains(&day) => Ok(day),
+        _ => Err(format!("Unknown day '{}' (expected 1-{})", value, NUM_DAYS)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_strs(args: &[&str]) -> Result<Command, String> {
+        parse(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_run_with_all_options() {
+        let command = parse_strs(&["run", "--day", "14", "--part", "2", "--input", "-", "--format", "json"]);
+        match command {
+            Ok(Command::Run(args)) => {
+                assert_eq!(args.day, 14);
+                assert_eq!(args.part, Some(Part::Two));
+                assert_eq!(args.input.as_deref(), Some("-"));
+                assert_eq!(args.format, Format::Json);
+                assert!(!args.trace);
+            },
+            _ => panic!("Expected a run command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_run_with_trace() {
+        match parse_strs(&["run", "--trace", "--day", "6"]) {
+            Ok(Command::Run(args)) => assert!(args.trace),
+            _ => panic!("Expected a run command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_run_with_mode() {
+        match parse_strs(&["run", "--day", "5", "--mode", "general"]) {
+            Ok(Command::Run(args)) => assert_eq!(args.mode.as_deref(), Some("general")),
+            _ => panic!("Expected a run command"),
+        }
+        assert!(parse_strs(&["run", "--day", "5", "--mode", "sideways"]).is_err());
+        assert!(parse_strs(&["run", "--day", "6", "--mode", "general"]).is_err());
+        assert!(parse_strs(&["render", "--day", "5", "--mode", "general"]).is_ok());
+        assert!(parse_strs(&["render", "--day", "11", "--mode", "puzzle"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_run_requires_day() {
+        assert!(parse_strs(&["run", "--part", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_run_rejects_out_of_range_day() {
+        assert!(parse_strs(&["run", "--day", "26"]).is_err());
+        assert!(parse_strs(&["run", "--day", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_defaults_to_every_day() {
+        match parse_strs(&["bench"]) {
+            Ok(Command::Bench(args)) => {
+                assert_eq!(args.days, (1..=25).collect::<Vec<_>>());
+                assert_eq!(args.iterations, DEFAULT_ITERATIONS);
+                assert_eq!(args.output, DEFAULT_BENCH_OUTPUT);
+            },
+            _ => panic!("Expected a bench command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_all() {
+        match parse_strs(&["all", "--jobs", "4", "--timeout", "30", "--format", "json"]) {
+            Ok(Command::All(args)) => {
+                assert_eq!(args.jobs, 4);
+                assert_eq!(args.timeout, Duration::from_secs(30));
+                assert_eq!(args.format, Format::Json);
+            },
+            _ => panic!("Expected an all command"),
+        }
+        match parse_strs(&["all"]) {
+            Ok(Command::All(args)) => assert_eq!(args.timeout, Duration::from_secs(DEFAULT_TIMEOUT_SECS)),
+            _ => panic!("Expected an all command"),
+        }
+        assert!(parse_strs(&["all", "--jobs", "0"]).is_err());
+        assert!(parse_strs(&["all", "--day", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_render() {
+        match parse_strs(&["render", "--day", "25", "--format", "png", "--palette", "#000000,#ffffff"]) {
+            Ok(Command::Render(args)) => {
+                assert_eq!(args.format, ImageFormat::Png);
+                assert!(args.palette.is_some());
+                assert_eq!(args.scale, None);
+            },
+            _ => panic!("Expected a render command"),
+        }
+        assert!(parse_strs(&["render", "--day", "5"]).is_ok());
+        assert!(parse_strs(&["render", "--day", "1"]).is_err());
+        assert!(parse_strs(&["render", "--day", "11", "--palette", "rainbow"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_validates_options() {
+        assert!(parse_strs(&["bench", "--iterations", "0"]).is_err());
+        assert!(parse_strs(&["bench", "--input", "example"]).is_err());
+        assert!(parse_strs(&["bench", "--day", "22", "--input", "example"]).is_ok());
+    }
+}
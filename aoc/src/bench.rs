@@ -0,0 +1,86 @@
+//! The `bench` command: times each phase of each chosen day, printing a summary and appending the
+//! results to a CSV file so they can be compared over time.
+
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+use aoc_common::bench::{format_duration, Phase, Stats};
+use aoc_common::solution::SolveError;
+use crate::args::BenchArgs;
+use crate::{days, read_input};
+
+const CSV_HEADER: &str = "timestamp,day,phase,iterations,min_ns,median_ns,max_ns";
+
+pub fn run(args: BenchArgs) -> ExitCode {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)
+        .expect("Could not get the time since the epoch")
+        .as_secs();
+
+    let mut rows = vec![];
+    for &day in &args.days {
+        let Some((input_path, input)) = read_input(day, args.input.clone()) else {
+            return ExitCode::FAILURE;
+        };
+        let bencher = days::bencher(day).expect("Day was validated when parsing args");
+        match bencher(&input, args.iterations) {
+            Ok(timings) => {
+                for timing in timings {
+                    let stats = timing.stats();
+                    println!("{}", format_stats(day, timing.phase, &stats));
+                    rows.push(csv_row(timestamp, day, timing.phase, &stats));
+                }
+            },
+            Err(SolveError::Parse(e)) => {
+                eprintln!("Invalid input for day {} in {}: {}", day, input_path, e);
+                return ExitCode::FAILURE;
+            },
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                return ExitCode::FAILURE;
+            },
+        }
+    }
+
+    if let Err(e) = append_csv(Path::new(&args.output), &rows) {
+        eprintln!("Could not write bench results to {}: {}", args.output, e);
+        return ExitCode::FAILURE;
+    }
+    println!("Results appended to {}", args.output);
+    ExitCode::SUCCESS
+}
+
+fn format_stats(day: u8, phase: Phase, stats: &Stats) -> String {
+    format!("Day {:2} {:5}  min {:>8}  median {:>8}  max {:>8}  ({} iterations)",
+            day,
+            phase.to_string(),
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.max),
+            stats.iterations)
+}
+
+fn csv_row(timestamp: u64, day: u8, phase: Phase, stats: &Stats) -> String {
+    format!("{},{},{},{},{},{},{}",
+            timestamp,
+            day,
+            phase,
+            stats.iterations,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.max.as_nanos())
+}
+
+// Appends the rows to the file, starting it with a header if it's new
+fn append_csv(path: &Path, rows: &[String]) -> io::Result<()> {
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if is_new {
+        writeln!(file, "{}", CSV_HEADER)?;
+    }
+    for row in rows {
+        writeln!(file, "{}", row)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+use aoc_common::bench::{bench, Bencher};
+use aoc_common::render::{animate, Animator};
+use aoc_common::solution::{solve, Solver};
+
+pub const NUM_DAYS: u8 = 25;
+
+const SOLVERS: [Solver; NUM_DAYS as usize] = [
+    solve::<day01::Day01>, solve::<day02::Day02>, solve::<day03::Day03>, solve::<day04::Day04>, solve::<day05::Day05>,
+    solve::<day06::Day06>, solve::<day07::Day07>, solve::<day08::Day08>, solve::<day09::Day09>, solve::<day10::Day10>,
+    solve::<day11::Day11>, solve::<day12::Day12>, solve::<day13::Day13>, solve::<day14::Day14>, solve::<day15::Day15>,
+    solve::<day16::Day16>, solve::<day17::Day17>, solve::<day18::Day18>, solve::<day19::Day19>, solve::<day20::Day20>,
+    solve::<day21::Day21>, solve::<day22::Day22>, solve::<day23::Day23>, solve::<day24::Day24>, solve::<day25::Day25>,
+];
+
+const BENCHERS: [Bencher; NUM_DAYS as usize] = [
+    bench::<day01::Day01>, bench::<day02::Day02>, bench::<day03::Day03>, bench::<day04::Day04>, bench::<day05::Day05>,
+    bench::<day06::Day06>, bench::<day07::Day07>, bench::<day08::Day08>, bench::<day09::Day09>, bench::<day10::Day10>,
+    bench::<day11::Day11>, bench::<day12::Day12>, bench::<day13::Day13>, bench::<day14::Day14>, bench::<day15::Day15>,
+    bench::<day16::Day16>, bench::<day17::Day17>, bench::<day18::Day18>, bench::<day19::Day19>, bench::<day20::Day20>,
+    bench::<day21::Day21>, bench::<day22::Day22>, bench::<day23::Day23>, bench::<day24::Day24>, bench::<day25::Day25>,
+];
+
+pub fn solver(day: u8) -> Option<Solver> {
+    SOLVERS.get((day as usize).checked_sub(1)?).copied()
+}
+
+pub fn bencher(day: u8) -> Option<Bencher> {
+    BENCHERS.get((day as usize).checked_sub(1)?).copied()
+}
+
+/// The modes a day can be solved in, for the days with more than one. The first is the puzzle's
+/// own, which is used when no mode is given.
+pub fn modes(day: u8) -> &'static [&'static str] {
+    match day {
+        5 => &["puzzle", "general"],
+        _ => &[],
+    }
+}
+
+/// Solves the day in the given mode (see `modes`), or as the puzzle does if there's none.
+pub fn mode_solver(day: u8, mode: Option<&str>) -> Option<Solver> {
+    match (day, mode) {
+        (_, None) | (5, Some("puzzle")) => solver(day),
+        (5, Some("general")) => Some(solve::<day05::Day05General>),
+        _ => None,
+    }
+}
+
+/// Draws the day's simulation in the given mode (see `modes`), for the days that have one.
+pub fn animator(day: u8, mode: Option<&str>) -> Option<Animator> {
+    match (day, mode) {
+        (5, None | Some("puzzle")) => Some(animate::<day05::Day05>),
+        (5, Some("general")) => Some(animate::<day05::Day05General>),
+        (11, None) => Some(animate::<day11::Day11>),
+        (13, None) => Some(animate::<day13::Day13>),
+        (20, None) => Some(animate::<day20::Day20>),
+        (25, None) => Some(animate::<day25::Day25>),
+        _ => None,
+    }
+}
+
+/// The day's crate directory, i.e. `dayNN`.
+pub fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{:02}", day))
+}
+
+/// The puzzle input checked in alongside the day's crate, i.e. `dayNN/input`.
+pub fn default_input_path(day: u8) -> PathBuf {
+    day_dir(day).join("input")
+}
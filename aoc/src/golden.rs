@@ -0,0 +1,79 @@
+//! Checks every day against the expected answers recorded in its `dayNN/answers.toml`, e.g.
+//!
+//! ```toml
+//! [[case]]
+//! input = "example"
+//! part1 = "37"
+//! part2 = "168"
+//! ```
+//!
+//! A part that is left out isn't checked (e.g. day 25 has no part 2).
+
+use serde::Deserialize;
+use aoc_common::{input, Part};
+use crate::days;
+
+#[derive(Deserialize)]
+struct Answers {
+    #[serde(rename = "case")]
+    cases: Vec<Case>,
+}
+
+#[derive(Deserialize)]
+struct Case {
+    input: String,
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// Runs each of the day's cases, returning a description of every answer that didn't match.
+fn check_day(day: u8) -> Vec<String> {
+    let dir = days::day_dir(day);
+    let answers: Answers = toml::from_str(&input::read_to_string(dir.join("answers.toml")))
+        .unwrap_or_else(|e| panic!("Could not parse day {} answers.toml: {}", day, e));
+    let solver = days::solver(day).expect("Could not find solver");
+
+    let mut failures = vec![];
+    for case in answers.cases {
+        let input = input::read_to_string(dir.join(&case.input));
+        for (part, expected) in [(Part::One, case.part1), (Part::Two, case.part2)] {
+            let Some(expected) = expected else { continue };
+            let actual = match solver(&input, part) {
+                Ok(Some(result)) => result.display,
+                Ok(None) => format!("(no part {})", part),
+                Err(e) => format!("({})", e),
+            };
+            if actual != expected {
+                failures.push(format!("day {} part {} on {}: expected {}, got {}", day, part, case.input, expected, actual));
+            }
+        }
+    }
+    failures
+}
+
+#[test]
+fn test_every_day_has_answers() {
+    for day in 1..=days::NUM_DAYS {
+        assert!(days::day_dir(day).join("answers.toml").exists(), "Day {} has no answers.toml", day);
+    }
+}
+
+macro_rules! golden_tests {
+    ($($name:ident: $day:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let failures = check_day($day);
+                assert!(failures.is_empty(), "{}", failures.join("\n"));
+            }
+        )*
+    };
+}
+
+golden_tests! {
+    day01: 1, day02: 2, day03: 3, day04: 4, day05: 5,
+    day06: 6, day07: 7, day08: 8, day09: 9, day10: 10,
+    day11: 11, day12: 12, day13: 13, day14: 14, day15: 15,
+    day16: 16, day17: 17, day18: 18, day19: 19, day20: 20,
+    day21: 21, day22: 22, day23: 23, day24: 24, day25: 25,
+}
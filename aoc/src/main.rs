@@ -0,0 +1,84 @@
+//! Runs any day's solution against a chosen input, e.g.
+//! `aoc run --day 14 --part 2 --input day14/example`, times it with `aoc bench --day 14`, or
+//! solves every day at once with `aoc all`
+
+use std::process::ExitCode;
+use std::sync::Arc;
+use aoc_common::trace::{self, StderrObserver};
+use aoc_common::solution::SolveError;
+use aoc_common::{input, Part};
+
+mod all;
+mod args;
+mod bench;
+mod days;
+mod output;
+mod render;
+#[cfg(test)]
+mod golden;
+#[cfg(test)]
+mod properties;
+
+use args::{Command, RunArgs};
+
+fn main() -> ExitCode {
+    match args::parse(std::env::args().skip(1)) {
+        Ok(Command::Run(run_args)) => run(run_args),
+        Ok(Command::Bench(bench_args)) => bench::run(bench_args),
+        Ok(Command::All(all_args)) => all::run(all_args),
+        Ok(Command::Render(render_args)) => render::run(render_args),
+        Ok(Command::Help) => {
+            println!("{}", args::USAGE);
+            ExitCode::SUCCESS
+        },
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, args::USAGE);
+            ExitCode::from(2)
+        },
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let solver = days::mode_solver(args.day, args.mode.as_deref()).expect("Day and mode were validated when parsing args");
+
+    let Some((input_path, input)) = read_input(args.day, args.input) else {
+        return ExitCode::FAILURE;
+    };
+    if args.trace {
+        trace::set_observer(Some(Arc::new(StderrObserver)));
+    }
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    for part in parts {
+        match solver(&input, part) {
+            Ok(Some(result)) => output::print_result(args.format, args.day, &result),
+            Ok(None) => eprintln!("Day {} has no part {}", args.day, part),
+            Err(SolveError::Parse(e)) => {
+                output::print_error(args.format, args.day, &input_path, &e);
+                return ExitCode::FAILURE;
+            },
+            Err(e) => {
+                output::print_failure(args.format, args.day, &e.to_string());
+                return ExitCode::FAILURE;
+            },
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Reads the given input file, or the day's own input if none was given, returning the path that
+/// was read along with its contents. Reports any failure on stderr.
+fn read_input(day: u8, input_path: Option<String>) -> Option<(String, String)> {
+    let input_path = input_path
+        .unwrap_or_else(|| days::default_input_path(day).to_string_lossy().into_owned());
+    match input::read(&input_path) {
+        Ok(input) => Some((input_path, input)),
+        Err(e) => {
+            eprintln!("Could not read input {}: {}", input_path, e);
+            None
+        },
+    }
+}
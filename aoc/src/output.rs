@@ -0,0 +1,79 @@
+//! Printing of results, either as text for people or as one JSON object per line for tools.
+
+use std::collections::BTreeMap;
+use serde::Serialize;
+use aoc_common::answer::PartResult;
+use aoc_common::{solution, ParseError};
+use crate::args::Format;
+
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    day: u8,
+    part: u8,
+    // Always a string, as some answers are too large for JSON numbers and day 13's is text
+    answer: &'a str,
+    parse_ns: u128,
+    elapsed_ns: u128,
+    metadata: BTreeMap<&'a str, &'a str>,
+}
+
+#[derive(Serialize)]
+struct JsonError<'a> {
+    day: u8,
+    input: &'a str,
+    error: &'a str,
+    text: Option<&'a str>,
+    line: Option<usize>,
+    column: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct JsonFailure<'a> {
+    day: u8,
+    error: &'a str,
+}
+
+pub fn print_result(format: Format, day: u8, result: &PartResult) {
+    match format {
+        Format::Text => println!("Day {} {}", day, solution::format_answer(result.part, &result.display)),
+        Format::Json => {
+            let json = JsonAnswer {
+                day,
+                part: result.part.number(),
+                answer: &result.value,
+                parse_ns: result.parse_elapsed.as_nanos(),
+                elapsed_ns: result.elapsed.as_nanos(),
+                metadata: result.metadata.iter().map(|(key, value)| (*key, value.as_str())).collect(),
+            };
+            println!("{}", serde_json::to_string(&json).expect("Could not serialise answer"));
+        },
+    }
+}
+
+pub fn print_error(format: Format, day: u8, input_path: &str, error: &ParseError) {
+    match format {
+        Format::Text => eprintln!("Invalid input for day {} in {}: {}", day, input_path, error),
+        Format::Json => {
+            let json = JsonError {
+                day,
+                input: input_path,
+                error: &error.message,
+                text: error.text.as_deref(),
+                line: error.location.as_ref().map(|location| location.line),
+                column: error.location.as_ref().map(|location| location.column),
+            };
+            println!("{}", serde_json::to_string(&json).expect("Could not serialise error"));
+        },
+    }
+}
+
+/// Reports a day that couldn't be solved for a reason other than invalid input, e.g. a timeout.
+pub fn print_failure(format: Format, day: u8, message: &str) {
+    match format {
+        Format::Text => eprintln!("Day {}: {}", day, message),
+        Format::Json => {
+            let json = JsonFailure { day, error: message };
+            println!("{}", serde_json::to_string(&json).expect("Could not serialise failure"));
+        },
+    }
+}
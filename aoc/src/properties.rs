@@ -0,0 +1,87 @@
+//! Property tests over every day, using the random inputs from `aoc-gen`: generated inputs parse
+//! and solve, and parsing anything else returns an error rather than panicking.
+
+use proptest::prelude::*;
+use proptest::sample::Index;
+use proptest::test_runner::{Config, TestCaseError, TestRunner};
+use aoc_common::{ParseResult, Part, Solution};
+use crate::days;
+
+type Parser = fn(&str) -> ParseResult<()>;
+
+fn parse<S: Solution>(input: &str) -> ParseResult<()> {
+    S::parse(input).map(|_| ()).map_err(|e| e.locate(input))
+}
+
+const PARSERS: [Parser; days::NUM_DAYS as usize] = [
+    parse::<day01::Day01>, parse::<day02::Day02>, parse::<day03::Day03>, parse::<day04::Day04>, parse::<day05::Day05>,
+    parse::<day06::Day06>, parse::<day07::Day07>, parse::<day08::Day08>, parse::<day09::Day09>, parse::<day10::Day10>,
+    parse::<day11::Day11>, parse::<day12::Day12>, parse::<day13::Day13>, parse::<day14::Day14>, parse::<day15::Day15>,
+    parse::<day16::Day16>, parse::<day17::Day17>, parse::<day18::Day18>, parse::<day19::Day19>, parse::<day20::Day20>,
+    parse::<day21::Day21>, parse::<day22::Day22>, parse::<day23::Day23>, parse::<day24::Day24>, parse::<day25::Day25>,
+];
+
+// Days whose solutions can't be checked on generated inputs: day 11's octopuses and day 25's sea
+// cucumbers may never settle, and days 19 and 23 are too slow to solve many times over
+const UNSOLVABLE_DAYS: [u8; 4] = [11, 19, 23, 25];
+
+fn check_every_day(cases: u32, test: impl Fn(u8, String) -> Result<(), TestCaseError>) {
+    for day in 1..=days::NUM_DAYS {
+        let strategy = aoc_gen::input(day).expect("Every day has a generator");
+        let mut runner = TestRunner::new(Config { cases, failure_persistence: None, ..Config::default() });
+        if let Err(e) = runner.run(&strategy, |input| test(day, input)) {
+            panic!("Day {}: {}", day, e);
+        }
+    }
+}
+
+// A generated input with one character replaced by another likely to upset a parser
+fn mutated(day: u8) -> impl Strategy<Value = String> {
+    let replacement = prop::sample::select(vec!['\n', ' ', ',', '-', '=', '.', '0', '9', '[', ']', '#', 'x', 'é']);
+    (aoc_gen::input(day).expect("Every day has a generator"), any::<Index>(), replacement)
+        .prop_map(|(input, index, replacement)| {
+            let mut chars = input.chars().collect::<Vec<_>>();
+            let i = index.index(chars.len());
+            chars[i] = replacement;
+            chars.into_iter().collect()
+        })
+}
+
+#[test]
+fn test_generated_inputs_parse() {
+    check_every_day(64, |day, input| {
+        let parsed = PARSERS[day as usize - 1](&input);
+        prop_assert!(parsed.is_ok(), "{:?} on input:\n{}", parsed, input);
+        Ok(())
+    });
+}
+
+#[test]
+fn test_generated_inputs_solve() {
+    check_every_day(16, |day, input| {
+        if UNSOLVABLE_DAYS.contains(&day) {
+            return Ok(());
+        }
+        let solver = days::solver(day).expect("Could not find solver");
+        for part in Part::ALL {
+            let solved = solver(&input, part);
+            prop_assert!(solved.is_ok(), "Part {}: {:?} on input:\n{}", part, solved, input);
+        }
+        Ok(())
+    });
+}
+
+#[test]
+fn test_parsers_never_panic() {
+    for (i, parser) in PARSERS.into_iter().enumerate() {
+        let day = i as u8 + 1;
+        let mut runner = TestRunner::new(Config { cases: 128, failure_persistence: None, ..Config::default() });
+        let inputs = prop_oneof![any::<String>(), "[0-9a-z ,=.>#\\-\n\\[\\]]*", mutated(day)];
+        if let Err(e) = runner.run(&inputs, |input| {
+            let _ = parser(&input);
+            Ok(())
+        }) {
+            panic!("Day {}: {}", day, e);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! The `render` command: draws every step of a day's simulation, as numbered PNG or PPM frames or
+//! as an animated GIF.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::str::FromStr;
+use aoc_common::render::{Frame, Palette};
+use crate::args::RenderArgs;
+use crate::{days, read_input};
+
+// Without a --scale, frames are scaled up to fit within roughly this many pixels
+const TARGET_SIZE: usize = 800;
+// The delay between GIF frames, in hundredths of a second
+const GIF_DELAY: u16 = 5;
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum ImageFormat {
+    Gif,
+    Png,
+    Ppm,
+}
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "gif" => Ok(ImageFormat::Gif),
+            "png" => Ok(ImageFormat::Png),
+            "ppm" => Ok(ImageFormat::Ppm),
+            _ => Err(format!("Unknown image format '{}' (expected gif, png or ppm)", s)),
+        }
+    }
+}
+
+pub fn run(args: RenderArgs) -> ExitCode {
+    let Some((input_path, input)) = read_input(args.day, args.input) else {
+        return ExitCode::FAILURE;
+    };
+    let animator = days::animator(args.day, args.mode.as_deref()).expect("Day and mode were validated when parsing args");
+    let (frames, default_palette) = match animator(&input) {
+        Ok(animation) => animation,
+        Err(e) => {
+            eprintln!("Invalid input for day {} in {}: {}", args.day, input_path, e);
+            return ExitCode::FAILURE;
+        },
+    };
+    let palette = args.palette.unwrap_or_else(|| Palette::named(default_palette).expect("Unknown default palette"));
+
+    let max_size = frames.iter().map(|f| f.width().max(f.height())).max().unwrap_or(1);
+    let scale = args.scale.unwrap_or((TARGET_SIZE / max_size).max(1));
+    let output = args.output.unwrap_or_else(|| match args.format {
+        ImageFormat::Gif => format!("day{:02}.gif", args.day),
+        _ => format!("day{:02}-frames", args.day),
+    });
+
+    let result = match args.format {
+        ImageFormat::Gif => write_gif(Path::new(&output), &frames, &palette, scale),
+        format => write_frames(Path::new(&output), format, &frames, &palette, scale),
+    };
+    match result {
+        Ok(()) => {
+            println!("Wrote {} frames to {}", frames.len(), output);
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            eprintln!("Could not write frames to {}: {}", output, e);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+// Writes each frame to its own file in `dir`, named frame-0000.png etc
+fn write_frames(dir: &Path, format: ImageFormat, frames: &[Frame], palette: &Palette, scale: usize) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    for (i, frame) in frames.iter().enumerate() {
+        let (width, height) = (frame.width() * scale, frame.height() * scale);
+        let pixels = scaled(frame, scale).iter()
+            .flat_map(|&level| palette.colour(level, frame.levels()))
+            .collect::<Vec<u8>>();
+        match format {
+            ImageFormat::Png => {
+                let file = BufWriter::new(File::create(dir.join(format!("frame-{:04}.png", i)))?);
+                let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder.write_header()?.write_image_data(&pixels)?;
+            },
+            ImageFormat::Ppm => {
+                let mut file = BufWriter::new(File::create(dir.join(format!("frame-{:04}.ppm", i)))?);
+                write!(file, "P6\n{} {}\n255\n", width, height)?;
+                file.write_all(&pixels)?;
+            },
+            ImageFormat::Gif => unreachable!("GIFs are written as a single file"),
+        }
+    }
+    Ok(())
+}
+
+// Writes all the frames to one looping GIF. Smaller frames are padded to the size of the largest.
+fn write_gif(path: &Path, frames: &[Frame], palette: &Palette, scale: usize) -> Result<(), Box<dyn Error>> {
+    let levels = frames.iter().map(|f| f.levels()).max().unwrap_or(2);
+    let colours = (0..levels).flat_map(|level| palette.colour(level, levels)).collect::<Vec<u8>>();
+    let width = frames.iter().map(|f| f.width()).max().unwrap_or(0);
+    let height = frames.iter().map(|f| f.height()).max().unwrap_or(0);
+    let (gif_width, gif_height) = (u16::try_from(width * scale)?, u16::try_from(height * scale)?);
+
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = gif::Encoder::new(file, gif_width, gif_height, &colours)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    for frame in frames {
+        let mut padded = Frame::new(width, height, levels);
+        for y in 0..frame.height() {
+            for x in 0..frame.width() {
+                padded.set(x, y, frame.get(x, y));
+            }
+        }
+        encoder.write_frame(&gif::Frame {
+            width: gif_width,
+            height: gif_height,
+            delay: GIF_DELAY,
+            buffer: Cow::Owned(scaled(&padded, scale)),
+            ..gif::Frame::default()
+        })?;
+    }
+    Ok(())
+}
+
+// The frame's levels, row by row, with each cell repeated to fill a `scale` x `scale` square
+fn scaled(frame: &Frame, scale: usize) -> Vec<u8> {
+    let mut levels = Vec::with_capacity(frame.width() * frame.height() * scale * scale);
+    for y in 0..frame.height() * scale {
+        for x in 0..frame.width() * scale {
+            levels.push(frame.get(x / scale, y / scale));
+        }
+    }
+    levels
+}
@@ -0,0 +1,165 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+use num::{Num, Zero};
+use aoc_common::parse::{parse_at, Location};
+use aoc_common::{ParseError, ParseResult, Solution};
+
+pub mod profile;
+
+pub struct Day01;
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> ParseResult<Vec<i32>> {
+        input.lines().map(|line| parse_at(line, "depth")).collect()
+    }
+
+    fn part1(nums: &Vec<i32>) -> u64 {
+        count_increases(nums.iter().copied())
+    }
+
+    fn part2(nums: &Vec<i32>) -> u64 {
+        count_increases(nums.iter().map(|&num| i64::from(num)).window_sums(3))
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u64> {
+    Ok(Day01::part1(&Day01::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u64> {
+    Ok(Day01::part2(&Day01::parse(input)?))
+}
+
+/// Counts the increases in the sums of each `size` consecutive depths, read a line at a time from
+/// `reader`, so only the window is ever kept in memory, however long the sweep. A `size` of 1
+/// counts increases in the depths themselves, as in part 1.
+///
+/// ```
+/// let sweep = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+/// assert_eq!(day01::count_window_increases(sweep.as_bytes(), 1), Ok(7));
+/// assert_eq!(day01::count_window_increases(sweep.as_bytes(), 3), Ok(5));
+/// ```
+pub fn count_window_increases(reader: impl BufRead, size: usize) -> ParseResult<u64> {
+    let mut error = None;
+    let depths = reader.lines().enumerate().map_while(|(index, line)| {
+        let depth = line
+            .map_err(|e| ParseError::new(format!("Could not read line {}: {}", index + 1, e)))
+            .and_then(|line| parse_at::<i32>(&line, "depth").map_err(|mut e| {
+                // The line isn't part of any input held in memory, so it's located here instead
+                e.location = Some(Location { line: index + 1, column: 1, line_text: line.clone() });
+                e
+            }));
+        depth.map_err(|e| error = Some(e)).ok().map(i64::from)
+    });
+    let increases = count_increases(depths.window_sums(size));
+    error.map_or(Ok(increases), Err)
+}
+
+/// Counts the items that are greater than the one before, reading each item only once.
+pub fn count_increases<T: PartialOrd>(items: impl IntoIterator<Item = T>) -> u64 {
+    let mut items = items.into_iter();
+    let Some(mut last) = items.next() else {
+        return 0;
+    };
+    let mut increases = 0;
+    for item in items {
+        if item > last {
+            increases += 1;
+        }
+        last = item;
+    }
+    increases
+}
+
+/// The sums of every run of `size` consecutive items from an iterator. Each sum is worked out from
+/// the last by adding the item entering the window and subtracting the one leaving it, so only the
+/// window is ever kept in memory, however long the input.
+///
+/// ```
+/// use day01::{count_increases, WindowSumsExt};
+///
+/// let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+/// assert_eq!(depths.into_iter().window_sums(3).collect::<Vec<_>>(), [607, 618, 618, 617, 647, 716, 769, 792]);
+/// assert_eq!(count_increases(depths.into_iter().window_sums(3)), 5);
+/// ```
+pub struct WindowSums<I: Iterator> {
+    items: I,
+    size: usize,
+    window: VecDeque<I::Item>,
+    sum: I::Item,
+}
+
+impl<I: Iterator> WindowSums<I> where I::Item: Num + Copy {
+    pub fn new(items: I, size: usize) -> WindowSums<I> {
+        assert!(size > 0, "Windows must hold at least one item");
+        WindowSums { items, size, window: VecDeque::with_capacity(size), sum: I::Item::zero() }
+    }
+}
+
+impl<I: Iterator> Iterator for WindowSums<I> where I::Item: Num + Copy {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.window.len() == self.size {
+            let entering = self.items.next()?;
+            let leaving = self.window.pop_front().expect("The window is full");
+            self.sum = self.sum - leaving + entering;
+            self.window.push_back(entering);
+        }
+        // The first window has to be filled before there's a sum
+        while self.window.len() < self.size {
+            let entering = self.items.next()?;
+            self.sum = self.sum + entering;
+            self.window.push_back(entering);
+        }
+        Some(self.sum)
+    }
+}
+
+/// Adds `window_sums` to every iterator of numbers.
+pub trait WindowSumsExt: Iterator + Sized where Self::Item: Num + Copy {
+    fn window_sums(self, size: usize) -> WindowSums<Self> {
+        WindowSums::new(self, size)
+    }
+}
+impl<I: Iterator> WindowSumsExt for I where I::Item: Num + Copy {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_sweep_has_no_increases() {
+        assert_eq!(part1(""), Ok(0));
+        assert_eq!(part2(""), Ok(0));
+    }
+
+    #[test]
+    fn test_window_sums_of_any_size() {
+        let depths = [1u64, 2, 3, 4, 5];
+        assert_eq!(depths.into_iter().window_sums(1).collect::<Vec<_>>(), depths);
+        assert_eq!(depths.into_iter().window_sums(2).collect::<Vec<_>>(), [3, 5, 7, 9]);
+        assert_eq!(depths.into_iter().window_sums(5).collect::<Vec<_>>(), [15]);
+        assert_eq!(depths.into_iter().window_sums(6).count(), 0);
+    }
+
+    #[test]
+    fn test_streamed_errors_are_located() {
+        let error = count_window_increases("1\n2\nx\n4\n".as_bytes(), 3).unwrap_err();
+        assert_eq!(error.location.map(|location| location.line), Some(3));
+        // Windows of extreme depths are summed without overflowing
+        let sweep = [i32::MAX, i32::MAX, i32::MAX, i32::MIN, 0].map(|depth| depth.to_string()).join("\n");
+        assert_eq!(count_window_increases(sweep.as_bytes(), 3), Ok(0));
+        assert_eq!(part2(&sweep), Ok(0));
+    }
+
+    #[test]
+    fn test_window_sums_stream_lazily() {
+        // An endless sonar sweep, of which only the first few windows are ever read
+        let sums = (1..).window_sums(3).take(3).collect::<Vec<i64>>();
+        assert_eq!(sums, [6, 9, 12]);
+    }
+}
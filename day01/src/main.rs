@@ -1,28 +1,39 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::process::ExitCode;
+use aoc_common::{input, solution, Solution};
+use day01::profile::DepthProfile;
+use day01::Day01;
 
-fn count_increases(nums: &Vec<i32>) -> u32 {
-    let mut increases = 0;
-    let mut last = nums.first().unwrap();
-    for num in nums {
-        if num > last {
-            increases += 1;
-        }
-        last = num;
+const USAGE: &str = "Usage: day01 [profile [--json]]";
+// How wide a range of changes in depth each bar of the profile's histogram covers
+const HISTOGRAM_BUCKET_WIDTH: i64 = 10;
+
+fn main() -> ExitCode {
+    let input = input::read_to_string("input");
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => solution::print_answers::<Day01>(&input),
+        ["profile"] => print_profile(&input, false),
+        ["profile", "--json"] => print_profile(&input, true),
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::from(2)
+        },
     }
-    increases
 }
 
-fn main() {
-    let reader = BufReader::new(File::open("src/input")
-        .expect("Cannot open input"));
-    let nums: Vec<i32> = reader.lines().map(|l| {
-        let line = l.expect("Could not read line");
-        line.parse::<i32>().expect("Could not parse number")
-    }).collect();
-
-    println!("Part 1: {}", count_increases(&nums));
-
-    let sums: Vec<i32> = nums.windows(3).map(|w| w.into_iter().sum::<i32>()).collect();
-    println!("Part 2: {}", count_increases(&sums));
+fn print_profile(input: &str, json: bool) -> ExitCode {
+    let depths = match Day01::parse(input) {
+        Ok(depths) => depths,
+        Err(e) => {
+            eprintln!("Invalid input: {}", e.locate(input));
+            return ExitCode::FAILURE;
+        },
+    };
+    let profile = DepthProfile::new(&depths, HISTOGRAM_BUCKET_WIDTH);
+    if json {
+        println!("{}", serde_json::to_string(&profile).expect("Could not serialise profile"));
+    } else {
+        print!("{}", profile);
+    }
+    ExitCode::SUCCESS
 }
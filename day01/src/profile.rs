@@ -0,0 +1,208 @@
+//! A description of the shape of a whole sonar sweep, beyond how often the depth increases.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::Serialize;
+
+/// A depth reading and its position in the sweep.
+#[derive(Serialize, Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Reading {
+    pub index: usize,
+    pub depth: i32,
+}
+
+/// Consecutive readings, from the first to the last inclusive.
+#[derive(Serialize, Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Run {
+    pub first: Reading,
+    pub last: Reading,
+}
+impl Run {
+    pub fn readings(&self) -> usize {
+        self.last.index - self.first.index + 1
+    }
+}
+
+/// How many times the depth changed by between `from` and `to` inclusive.
+#[derive(Serialize, Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Bucket {
+    pub from: i64,
+    pub to: i64,
+    pub count: usize,
+}
+
+#[derive(Serialize, Eq, PartialEq, Clone, Debug)]
+pub struct DepthProfile {
+    pub readings: usize,
+    pub shallowest: Option<i32>,
+    pub deepest: Option<i32>,
+    /// The longest run where every reading is deeper than the one before, if there's any increase.
+    pub longest_increasing_run: Option<Run>,
+    pub longest_decreasing_run: Option<Run>,
+    /// Every run of two or more readings at the same depth.
+    pub plateaus: Vec<Run>,
+    /// Readings shallower than the readings either side of them.
+    pub local_minima: Vec<Reading>,
+    /// Readings deeper than the readings either side of them.
+    pub local_maxima: Vec<Reading>,
+    /// The changes in depth between consecutive readings, in buckets of equal width, from the
+    /// biggest decrease to the biggest increase. The buckets are made wider than asked for if that
+    /// would take more than `MAX_BUCKETS` of them.
+    pub delta_histogram: Vec<Bucket>,
+}
+
+impl DepthProfile {
+    pub fn new(depths: &[i32], bucket_width: i64) -> DepthProfile {
+        assert!(bucket_width > 0, "Histogram buckets must be at least 1 wide");
+        let reading = |index: usize| Reading { index, depth: depths[index] };
+
+        let local_extrema = |is_extreme: fn(i32, i32, i32) -> bool| {
+            depths.windows(3).enumerate()
+                .filter(|(_, w)| is_extreme(w[0], w[1], w[2]))
+                .map(|(i, _)| reading(i + 1))
+                .collect::<Vec<_>>()
+        };
+
+        DepthProfile {
+            readings: depths.len(),
+            shallowest: depths.iter().min().copied(),
+            deepest: depths.iter().max().copied(),
+            longest_increasing_run: longest(runs(depths, |a, b| b > a)),
+            longest_decreasing_run: longest(runs(depths, |a, b| b < a)),
+            plateaus: runs(depths, |a, b| a == b).collect(),
+            local_minima: local_extrema(|before, depth, after| depth < before && depth < after),
+            local_maxima: local_extrema(|before, depth, after| depth > before && depth > after),
+            // Changes between depths of opposite signs can be too big for an i32
+            delta_histogram: histogram(depths.windows(2).map(|w| i64::from(w[1]) - i64::from(w[0])), bucket_width),
+        }
+    }
+}
+
+// The maximal runs of two or more readings where each pair of neighbours satisfies `continues`
+fn runs(depths: &[i32], continues: fn(i32, i32) -> bool) -> impl Iterator<Item = Run> + '_ {
+    let mut start = 0;
+    (1..=depths.len()).filter_map(move |end| {
+        // A run ends at the end of the sweep, or where the next pair doesn't continue it
+        if end < depths.len() && continues(depths[end - 1], depths[end]) {
+            return None;
+        }
+        let run = Run {
+            first: Reading { index: start, depth: depths[start] },
+            last: Reading { index: end - 1, depth: depths[end - 1] },
+        };
+        start = end;
+        (run.readings() > 1).then_some(run)
+    })
+}
+
+// The first of the longest runs
+fn longest(runs: impl Iterator<Item = Run>) -> Option<Run> {
+    runs.fold(None, |longest, run| {
+        if longest.is_none_or(|longest: Run| run.readings() > longest.readings()) { Some(run) } else { longest }
+    })
+}
+
+/// The most buckets a depth profile's histogram is split into.
+pub const MAX_BUCKETS: usize = 50;
+
+fn histogram(deltas: impl Iterator<Item = i64>, bucket_width: i64) -> Vec<Bucket> {
+    let deltas = deltas.collect::<Vec<_>>();
+    let (Some(&smallest), Some(&largest)) = (deltas.iter().min(), deltas.iter().max()) else {
+        return vec![];
+    };
+    // Buckets start at multiples of their width, so the changes can straddle one more bucket than
+    // their range divided by the width
+    let bucket_width = bucket_width.max(((largest - smallest + 1) as u64).div_ceil(MAX_BUCKETS as u64 - 1) as i64);
+    let mut counts = BTreeMap::new();
+    for delta in deltas {
+        *counts.entry(delta.div_euclid(bucket_width)).or_insert(0) += 1;
+    }
+    // Buckets between the smallest and largest changes are included even if they're empty
+    let (lowest, highest) = (smallest.div_euclid(bucket_width), largest.div_euclid(bucket_width));
+    (lowest..=highest).map(|bucket| Bucket {
+        from: bucket * bucket_width,
+        to: bucket * bucket_width + bucket_width - 1,
+        count: counts.get(&bucket).copied().unwrap_or(0),
+    }).collect()
+}
+
+// The widest bar in the table's histogram, in characters
+const BAR_WIDTH: usize = 40;
+
+impl fmt::Display for DepthProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let optional = |depth: Option<i32>| depth.map_or("-".to_string(), |d| d.to_string());
+        let run = |run: Option<Run>| run.map_or("-".to_string(), |run| {
+            format!("{} readings, from {} at #{} to {} at #{}", run.readings(), run.first.depth, run.first.index, run.last.depth, run.last.index)
+        });
+        let longest_plateau = self.plateaus.iter().map(Run::readings).max().unwrap_or(0);
+
+        writeln!(f, "{:<20} {}", "Readings", self.readings)?;
+        writeln!(f, "{:<20} {}", "Shallowest", optional(self.shallowest))?;
+        writeln!(f, "{:<20} {}", "Deepest", optional(self.deepest))?;
+        writeln!(f, "{:<20} {}", "Longest increase", run(self.longest_increasing_run))?;
+        writeln!(f, "{:<20} {}", "Longest decrease", run(self.longest_decreasing_run))?;
+        writeln!(f, "{:<20} {} (longest {} readings)", "Plateaus", self.plateaus.len(), longest_plateau)?;
+        writeln!(f, "{:<20} {}", "Local minima", self.local_minima.len())?;
+        writeln!(f, "{:<20} {}", "Local maxima", self.local_maxima.len())?;
+        writeln!(f, "Changes in depth:")?;
+        let most = self.delta_histogram.iter().map(|bucket| bucket.count).max().unwrap_or(0);
+        for bucket in &self.delta_histogram {
+            let bar = "#".repeat((bucket.count * BAR_WIDTH).div_ceil(most));
+            writeln!(f, "{:>6}..={:<6} {:>6} {}", bucket.from, bucket.to, bucket.count, bar)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_of_example() {
+        let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let profile = DepthProfile::new(&depths, 10);
+        assert_eq!(profile.longest_increasing_run.map(|run| (run.first.index, run.readings())), Some((0, 4)));
+        assert_eq!(profile.longest_decreasing_run.map(|run| (run.first.index, run.readings())), Some((3, 2)));
+        assert_eq!(profile.local_minima, [Reading { index: 4, depth: 200 }, Reading { index: 8, depth: 260 }]);
+        assert_eq!(profile.local_maxima, [Reading { index: 3, depth: 210 }, Reading { index: 7, depth: 269 }]);
+        assert_eq!(profile.delta_histogram, [
+            Bucket { from: -10, to: -1, count: 2 },
+            Bucket { from: 0, to: 9, count: 5 },
+            Bucket { from: 10, to: 19, count: 0 },
+            Bucket { from: 20, to: 29, count: 1 },
+            Bucket { from: 30, to: 39, count: 1 },
+        ]);
+    }
+
+    #[test]
+    fn test_plateaus() {
+        let profile = DepthProfile::new(&[5, 5, 5, 6, 7, 7], 1);
+        assert_eq!(profile.plateaus.iter().map(|run| (run.first.index, run.readings())).collect::<Vec<_>>(), [(0, 3), (4, 2)]);
+        assert_eq!(profile.longest_increasing_run.map(|run| (run.first.index, run.readings())), Some((2, 3)));
+        assert_eq!(profile.longest_decreasing_run, None);
+    }
+
+    #[test]
+    fn test_outliers_widen_buckets() {
+        let profile = DepthProfile::new(&[100, 103, 98, 20_000_000, 20_000_004, 0], 10);
+        let histogram = &profile.delta_histogram;
+        assert!(histogram.len() <= MAX_BUCKETS);
+        assert!(histogram[0].from <= -20_000_004 && histogram[histogram.len() - 1].to >= 19_999_902);
+        assert!(histogram.windows(2).all(|pair| pair[1].from == pair[0].to + 1));
+        assert_eq!(histogram.iter().map(|bucket| bucket.count).sum::<usize>(), 5);
+        // The small changes all land in the buckets either side of 0
+        let near_zero = histogram.iter().filter(|bucket| bucket.to >= -5 && bucket.from <= 4);
+        assert_eq!(near_zero.map(|bucket| bucket.count).sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn test_extreme_changes() {
+        let profile = DepthProfile::new(&[i32::MIN, i32::MAX, i32::MIN], 1 << 32);
+        assert_eq!(profile.delta_histogram, [
+            Bucket { from: -(1 << 32), to: -1, count: 1 },
+            Bucket { from: 0, to: (1 << 32) - 1, count: 1 },
+        ]);
+    }
+}
@@ -0,0 +1,248 @@
+use std::fmt;
+use aoc_common::parse::{parse_at, split_once_at};
+use aoc_common::{ParseError, ParseResult, Solution};
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Command {
+    Forward(u32),
+    Down(u32),
+    Up(u32),
+}
+impl Command {
+    // e.g. "forward 5"
+    pub fn parse(line: &str) -> ParseResult<Command> {
+        let (name, distance) = split_once_at(line, " ", "command")?;
+        let command = match name {
+            "forward" => Command::Forward,
+            "down" => Command::Down,
+            "up" => Command::Up,
+            _ => return Err(ParseError::at(name, "Unknown command (expected forward, down or up)")),
+        };
+        Ok(command(parse_at(distance, "distance")?))
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(distance) => write!(f, "forward {}", distance),
+            Command::Down(distance) => write!(f, "down {}", distance),
+            Command::Up(distance) => write!(f, "up {}", distance),
+        }
+    }
+}
+
+/// Where the submarine is. Depth increases downwards, so it's negative above the surface.
+#[derive(Eq, PartialEq, Copy, Clone, Default, Debug)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+}
+
+/// A command that took the submarine further than its position can count.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Overflow {
+    /// The index of the command among those followed.
+    pub step: usize,
+    pub command: Command,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Position out of range after command {} ({})", self.step + 1, self.command)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// One way of interpreting the submarine's commands, tracking where they take it.
+pub trait Navigator: Default {
+    /// Moves the submarine, or returns `None` if its position would be out of range, in which case
+    /// the navigator may have been partly updated.
+    fn apply(&mut self, command: Command) -> Option<()>;
+
+    fn position(&self) -> Position;
+
+    /// Follows every command from the surface, returning the navigator at the end.
+    fn navigate(commands: &[Command]) -> Result<Self, Overflow> {
+        let mut navigator = Self::default();
+        for (step, &command) in commands.iter().enumerate() {
+            navigator.apply(command).ok_or(Overflow { step, command })?;
+        }
+        Ok(navigator)
+    }
+}
+
+/// Up and down change the depth directly.
+#[derive(Default, Debug)]
+pub struct SimpleNavigator {
+    position: Position,
+}
+impl Navigator for SimpleNavigator {
+    fn apply(&mut self, command: Command) -> Option<()> {
+        let position = &mut self.position;
+        match command {
+            Command::Forward(distance) => position.horizontal = position.horizontal.checked_add(distance.into())?,
+            Command::Down(distance) => position.depth = position.depth.checked_add(distance.into())?,
+            Command::Up(distance) => position.depth = position.depth.checked_sub(distance.into())?,
+        }
+        Some(())
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+}
+
+/// Up and down change the aim, and moving forward changes the depth by the aim.
+#[derive(Default, Debug)]
+pub struct AimNavigator {
+    position: Position,
+    aim: i64,
+}
+impl Navigator for AimNavigator {
+    fn apply(&mut self, command: Command) -> Option<()> {
+        let position = &mut self.position;
+        match command {
+            Command::Forward(distance) => {
+                let distance = i64::from(distance);
+                position.horizontal = position.horizontal.checked_add(distance)?;
+                position.depth = position.depth.checked_add(distance.checked_mul(self.aim)?)?;
+            },
+            Command::Down(distance) => self.aim = self.aim.checked_add(distance.into())?,
+            Command::Up(distance) => self.aim = self.aim.checked_sub(distance.into())?,
+        }
+        Some(())
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+}
+
+/// Wraps another navigator, recording the submarine's position at the start and after every
+/// command.
+#[derive(Debug)]
+pub struct Trajectory<N> {
+    navigator: N,
+    positions: Vec<Position>,
+}
+
+impl<N: Navigator> Trajectory<N> {
+    pub fn positions(&self) -> &[Position] {
+        &self.positions
+    }
+
+    /// The positions as CSV, with a header row, one row per step.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,horizontal,depth\n");
+        for (step, position) in self.positions.iter().enumerate() {
+            csv += &format!("{},{},{}\n", step, position.horizontal, position.depth);
+        }
+        csv
+    }
+}
+
+impl<N: Navigator> Default for Trajectory<N> {
+    fn default() -> Self {
+        let navigator = N::default();
+        let positions = vec![navigator.position()];
+        Trajectory { navigator, positions }
+    }
+}
+
+impl<N: Navigator> Navigator for Trajectory<N> {
+    fn apply(&mut self, command: Command) -> Option<()> {
+        self.navigator.apply(command)?;
+        self.positions.push(self.navigator.position());
+        Some(())
+    }
+
+    fn position(&self) -> Position {
+        self.navigator.position()
+    }
+}
+
+// The product of the final horizontal position and depth, which is wide enough not to overflow.
+// Each part checks its own course, as one interpretation of the commands may stay in range when
+// the other doesn't
+fn final_position_product<N: Navigator>(commands: &[Command]) -> Result<i128, Overflow> {
+    let Position { horizontal, depth } = N::navigate(commands)?.position();
+    Ok(i128::from(horizontal) * i128::from(depth))
+}
+
+pub struct Day02;
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    type Answer1 = Result<i128, Overflow>;
+    type Answer2 = Result<i128, Overflow>;
+
+    fn parse(input: &str) -> ParseResult<Vec<Command>> {
+        input.lines().map(Command::parse).collect()
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<i128, Overflow> {
+        final_position_product::<SimpleNavigator>(commands)
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<i128, Overflow> {
+        final_position_product::<AimNavigator>(commands)
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Result<i128, Overflow>> {
+    Ok(Day02::part1(&Day02::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<Result<i128, Overflow>> {
+    Ok(Day02::part2(&Day02::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{solution, Part};
+    use super::*;
+
+    const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+
+    #[test]
+    fn test_navigators_on_example() {
+        let commands = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(SimpleNavigator::navigate(&commands).unwrap().position(), Position { horizontal: 15, depth: 10 });
+        assert_eq!(AimNavigator::navigate(&commands).unwrap().position(), Position { horizontal: 15, depth: 60 });
+    }
+
+    #[test]
+    fn test_above_the_surface() {
+        let input = "up 5\nforward 2";
+        assert_eq!(part1(input), Ok(Ok(-10)));
+        assert_eq!(part2(input), Ok(Ok(-20)));
+    }
+
+    #[test]
+    fn test_overflow_only_fails_its_part() {
+        // Only the aim navigator multiplies the distances, so only part 2 goes out of range
+        let input = "down 4000000000\ndown 4000000000\nforward 4000000000\nforward 1";
+        assert_eq!(part1(input), Ok(Ok(4_000_000_001 * 8_000_000_000)));
+        assert_eq!(part2(input), Ok(Err(Overflow { step: 2, command: Command::Forward(4_000_000_000) })));
+        let error = solution::solve::<Day02>(input, Part::Two).unwrap_err();
+        assert_eq!(error.to_string(), "Could not solve part 2: Position out of range after command 3 (forward 4000000000)");
+    }
+
+    #[test]
+    fn test_trajectory_csv() {
+        let commands = Day02::parse(EXAMPLE).unwrap();
+        let trajectory = Trajectory::<AimNavigator>::navigate(&commands).unwrap();
+        assert_eq!(trajectory.positions().len(), commands.len() + 1);
+        assert_eq!(trajectory.to_csv(), "step,horizontal,depth\n0,0,0\n1,5,0\n2,5,0\n3,13,40\n4,13,40\n5,13,40\n6,15,60\n");
+    }
+
+    #[test]
+    fn test_unknown_command_is_located() {
+        let input = "forward 5\nbackward 2\nup 3";
+        let error = Day02::parse(input).unwrap_err().locate(input);
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (2, 1));
+        assert_eq!(error.text.as_deref(), Some("backward"));
+    }
+}
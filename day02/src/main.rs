@@ -1,36 +1,40 @@
-use std::fs::File;
-use std::io::{BufReader, BufRead};
+use std::process::ExitCode;
+use aoc_common::{input, solution, Solution};
+use day02::{AimNavigator, Day02, Navigator, SimpleNavigator, Trajectory};
 
-fn main() {
-    let reader = BufReader::new(File::open("input")
-        .expect("Cannot open input"));
+const USAGE: &str = "Usage: day02 [trajectory <simple|aim>]";
 
-    let mut horizontal = 0;
-    let mut depth1 = 0;
-    let mut aim = 0;
-    let mut depth2 = 0;
-    for line in reader.lines() {
-        let line = line.expect("Could not unrwap line");
-        match line {
-            _ if line.starts_with("forward ") => {
-                let num = line[8..].parse::<u32>().expect(&format!("Could not parse {}", line));
-                horizontal += num;
-                depth2 += num * aim;
-            },
-            _ if line.starts_with("down ") => {
-                let num = line[5..].parse::<u32>().expect(&format!("Could not parse {}", line));
-                depth1 += num;
-                aim += num;
-            },
-            _ if line.starts_with("up ") => {
-                let num = line[3..].parse::<u32>().expect(&format!("Could not parse {}", line));
-                depth1 -= num;
-                aim -= num;
-            },
-            _ => panic!("Unexpected input: {}", line)
-        }
+fn main() -> ExitCode {
+    let input = input::read_to_string("input");
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => solution::print_answers::<Day02>(&input),
+        ["trajectory", "simple"] => print_trajectory::<SimpleNavigator>(&input),
+        ["trajectory", "aim"] => print_trajectory::<AimNavigator>(&input),
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::from(2)
+        },
     }
+}
 
-    println!("Part 1: {}", horizontal * depth1);
-    println!("Part 2: {}", horizontal * depth2);
+// Prints the submarine's position after every command as CSV, for plotting
+fn print_trajectory<N: Navigator>(input: &str) -> ExitCode {
+    let commands = match Day02::parse(input) {
+        Ok(commands) => commands,
+        Err(e) => {
+            eprintln!("Invalid input: {}", e.locate(input));
+            return ExitCode::FAILURE;
+        },
+    };
+    match Trajectory::<N>::navigate(&commands) {
+        Ok(trajectory) => {
+            print!("{}", trajectory.to_csv());
+            ExitCode::SUCCESS
+        },
+        Err(overflow) => {
+            eprintln!("{}", overflow);
+            ExitCode::FAILURE
+        },
+    }
 }
@@ -0,0 +1,111 @@
+use aoc_common::{ParseError, ParseResult, Solution};
+use trie::BitTrie;
+
+pub mod trie;
+
+/// The most bits a diagnostic number may have, so that the ratings fit in a `u64` and their
+/// products in a `u128`.
+pub const MAX_WIDTH: u32 = 64;
+
+/// The submarine's diagnostic numbers, which all have the same number of bits.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Report {
+    pub width: u32,
+    pub numbers: Vec<u64>,
+}
+
+impl Report {
+    fn is_mostly_one_at(numbers: &[u64], bit: u32) -> bool {
+        numbers.iter().filter(|&&number| number >> bit & 1 == 1).count() * 2 >= numbers.len()
+    }
+
+    // The bits that any number in the report can have set
+    fn mask(&self) -> u64 {
+        u64::MAX >> (u64::BITS - self.width)
+    }
+}
+
+pub struct Day03;
+impl Solution for Day03 {
+    type Input = Report;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> ParseResult<Report> {
+        let first = input.lines().next().ok_or_else(|| ParseError::new("Expected at least one diagnostic number"))?;
+        let width = first.len() as u32;
+        if !(1..=MAX_WIDTH).contains(&width) {
+            return Err(ParseError::at(first, format!("Expected between 1 and {} bits", MAX_WIDTH)));
+        }
+        let numbers = input.lines().map(|line| {
+            if let Some((index, c)) = line.char_indices().find(|&(_, c)| c != '0' && c != '1') {
+                return Err(ParseError::at(&line[index..index + c.len_utf8()], "Expected a binary digit"));
+            }
+            if line.len() != width as usize {
+                return Err(ParseError::at(line, format!("Expected {} bits, as on the first line", width)));
+            }
+            Ok(u64::from_str_radix(line, 2).expect("Checked the digits"))
+        }).collect::<ParseResult<_>>()?;
+        Ok(Report { width, numbers })
+    }
+
+    fn part1(report: &Report) -> u128 {
+        power_consumption(report)
+    }
+
+    fn part2(report: &Report) -> u128 {
+        life_support_rating(report)
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u128> {
+    Ok(Day03::part1(&Day03::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u128> {
+    Ok(Day03::part2(&Day03::parse(input)?))
+}
+
+pub fn power_consumption(report: &Report) -> u128 {
+    let gamma_rate = (0..report.width)
+        .filter(|&bit| Report::is_mostly_one_at(&report.numbers, bit))
+        .fold(0, |gamma_rate, bit| gamma_rate | 1 << bit);
+    let epsilon_rate = !gamma_rate & report.mask();
+    u128::from(gamma_rate) * u128::from(epsilon_rate)
+}
+
+pub fn life_support_rating(report: &Report) -> u128 {
+    let trie = BitTrie::from_numbers(report.width, report.numbers.iter().copied());
+    let oxygen_generator_rating = trie.most_common().expect("There's at least one number");
+    let co2_scrubber_rating = trie.least_common().expect("There's at least one number");
+    u128::from(oxygen_generator_rating) * u128::from(co2_scrubber_rating)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width_is_inferred() {
+        let report = Day03::parse("1000\n1100\n0011").unwrap();
+        assert_eq!(report, Report { width: 4, numbers: vec![0b1000, 0b1100, 0b0011] });
+        assert_eq!(power_consumption(&report), 0b1000 * 0b0111);
+
+        let wide = format!("{}\n{}", "1".repeat(64), "0".repeat(64));
+        assert_eq!(Day03::parse(&wide).unwrap().numbers, [u64::MAX, 0]);
+    }
+
+    #[test]
+    fn test_co2_keeps_candidates_sharing_a_bit() {
+        // Neither number has the least common first bit, so both are kept for the second
+        assert_eq!(part2("10\n11"), Ok(0b11 * 0b10));
+    }
+
+    #[test]
+    fn test_lines_must_have_equal_widths() {
+        let input = "10110\n0111\n10101";
+        let error = Day03::parse(input).unwrap_err().locate(input);
+        assert_eq!(error.location.map(|location| location.line), Some(2));
+        assert_eq!(error.message, "Expected 5 bits, as on the first line");
+    }
+}
@@ -0,0 +1,139 @@
+//! A binary trie of equal-width numbers, for finding numbers by the bits they share with others.
+
+/// Numbers of the same width, stored most significant bit first, with how many numbers lie under
+/// each node, so any walk from the root to a number picking one bit at a time takes one step per
+/// bit.
+///
+/// ```
+/// use day03::trie::BitTrie;
+///
+/// let trie = BitTrie::from_numbers(5, [0b00100, 0b11110, 0b10110, 0b10111, 0b10101, 0b01111]);
+/// assert_eq!(trie.most_common(), Some(0b10111));
+/// assert_eq!(trie.least_common(), Some(0b00100));
+/// assert_eq!(trie.count_with_prefix(0b101, 3), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BitTrie {
+    width: u32,
+    // The root is first. A child of 0 means there's no child, as the root is no node's child
+    nodes: Vec<Node>,
+}
+
+#[derive(Clone, Default, Debug)]
+struct Node {
+    children: [usize; 2],
+    count: usize,
+}
+
+impl BitTrie {
+    pub fn new(width: u32) -> BitTrie {
+        assert!((1..=u64::BITS).contains(&width), "Numbers must be between 1 and 64 bits wide");
+        BitTrie { width, nodes: vec![Node::default()] }
+    }
+
+    pub fn from_numbers(width: u32, numbers: impl IntoIterator<Item = u64>) -> BitTrie {
+        let mut trie = BitTrie::new(width);
+        numbers.into_iter().for_each(|number| trie.insert(number));
+        trie
+    }
+
+    /// Adds a number, which may already be in the trie. Bits beyond the trie's width are ignored.
+    pub fn insert(&mut self, number: u64) {
+        let mut node = 0;
+        self.nodes[node].count += 1;
+        for bit in (0..self.width).rev() {
+            let branch = (number >> bit & 1) as usize;
+            if self.nodes[node].children[branch] == 0 {
+                self.nodes[node].children[branch] = self.nodes.len();
+                self.nodes.push(Node::default());
+            }
+            node = self.nodes[node].children[branch];
+            self.nodes[node].count += 1;
+        }
+    }
+
+    /// How many numbers have been inserted, including repeats.
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// How many numbers start with the `bits` lowest bits of `prefix`.
+    pub fn count_with_prefix(&self, prefix: u64, bits: u32) -> usize {
+        assert!(bits <= self.width, "The prefix is wider than the numbers");
+        let mut node = 0;
+        for bit in (0..bits).rev() {
+            node = self.nodes[node].children[(prefix >> bit & 1) as usize];
+            if node == 0 {
+                return 0;
+            }
+        }
+        self.nodes[node].count
+    }
+
+    /// Walks from the root to a number, calling `choose` with how many numbers have a 0 and a 1
+    /// next, given the bits so far, to pick the next bit. Where only one bit is possible it's taken
+    /// without asking, so the walk always ends at a number in the trie, or `None` if it's empty.
+    pub fn walk(&self, mut choose: impl FnMut(usize, usize) -> bool) -> Option<u64> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut node = 0;
+        let mut number = 0;
+        for _ in 0..self.width {
+            let [zeros, ones] = self.nodes[node].children.map(|child| if child == 0 { 0 } else { self.nodes[child].count });
+            let branch = match (zeros, ones) {
+                (_, 0) => 0,
+                (0, _) => 1,
+                _ => choose(zeros, ones) as usize,
+            };
+            node = self.nodes[node].children[branch];
+            number = number << 1 | branch as u64;
+        }
+        Some(number)
+    }
+
+    /// The number found by following the most common bit at each step, or 1 where they're equally
+    /// common.
+    pub fn most_common(&self) -> Option<u64> {
+        self.walk(|zeros, ones| ones >= zeros)
+    }
+
+    /// The number found by following the least common bit at each step, or 0 where they're equally
+    /// common.
+    pub fn least_common(&self) -> Option<u64> {
+        self.walk(|zeros, ones| ones < zeros)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_numbers_are_counted() {
+        let trie = BitTrie::from_numbers(3, [0b011, 0b011, 0b011, 0b100, 0b101]);
+        assert_eq!(trie.len(), 5);
+        assert_eq!(trie.count_with_prefix(0b01, 2), 3);
+        assert_eq!(trie.count_with_prefix(0b1, 1), 2);
+        assert_eq!(trie.count_with_prefix(0b11, 2), 0);
+        assert_eq!(trie.count_with_prefix(0, 0), 5);
+        assert_eq!(trie.most_common(), Some(0b011));
+        assert_eq!(trie.least_common(), Some(0b100));
+    }
+
+    #[test]
+    fn test_walks_end_at_numbers_in_the_trie() {
+        assert_eq!(BitTrie::new(4).most_common(), None);
+        let trie = BitTrie::from_numbers(64, [u64::MAX]);
+        assert_eq!(trie.least_common(), Some(u64::MAX));
+        // The walk asks at every bit where the numbers differ, and at no others
+        let trie = BitTrie::from_numbers(4, [0b0000, 0b0011, 0b0110]);
+        let mut asked = 0;
+        assert_eq!(trie.walk(|_, _| { asked += 1; false }), Some(0b0000));
+        assert_eq!(asked, 2);
+    }
+}
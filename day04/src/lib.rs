@@ -0,0 +1,385 @@
+use std::collections::HashMap;
+use std::fmt;
+use aoc_common::parse::parse_at;
+use aoc_common::{ParseError, ParseResult, Solution};
+
+pub mod strategy;
+
+#[derive(Copy, Clone, Debug)]
+struct BoardNum {
+    num: u32,
+    is_marked: bool
+}
+
+/// A square board of any size, stored row by row. How many numbers are marked in each line is kept
+/// up to date as they're marked, so whether the board has won is known without looking at it.
+#[derive(Clone, Debug)]
+pub struct Board {
+    size: usize,
+    nums: Vec<BoardNum>,
+    row_hits: Vec<usize>,
+    col_hits: Vec<usize>,
+    // The diagonal from the top left, then the one from the top right
+    diagonal_hits: [usize; 2],
+    has_full_row_or_col: bool,
+    sum_of_unmarked: u32,
+}
+impl Board {
+    pub fn new(size: usize, nums: &[u32]) -> Board {
+        assert_eq!(nums.len(), size * size, "Wrong number of numbers for a {}x{} board", size, size);
+        Board {
+            size,
+            nums: nums.iter().map(|&num| BoardNum { num, is_marked: false }).collect(),
+            row_hits: vec![0; size],
+            col_hits: vec![0; size],
+            diagonal_hits: [0; 2],
+            has_full_row_or_col: false,
+            sum_of_unmarked: nums.iter().sum(),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The board's numbers, row by row.
+    pub fn numbers(&self) -> impl Iterator<Item = u32> + '_ {
+        self.nums.iter().map(|board_num| board_num.num)
+    }
+
+    pub fn mark_if_present(&mut self, number: u32) {
+        for index in 0..self.nums.len() {
+            if self.nums[index].num == number {
+                self.mark(index);
+            }
+        }
+    }
+
+    /// Marks the number in the cell at `index` (counting row by row), if it isn't already marked.
+    pub fn mark(&mut self, index: usize) {
+        let board_num = &mut self.nums[index];
+        if board_num.is_marked {
+            return;
+        }
+        board_num.is_marked = true;
+        self.sum_of_unmarked -= board_num.num;
+
+        let (row, col) = (index / self.size, index % self.size);
+        self.row_hits[row] += 1;
+        self.col_hits[col] += 1;
+        self.has_full_row_or_col |= self.row_hits[row] == self.size || self.col_hits[col] == self.size;
+        if row == col {
+            self.diagonal_hits[0] += 1;
+        }
+        if row + col == self.size - 1 {
+            self.diagonal_hits[1] += 1;
+        }
+    }
+
+    /// The numbers in each line that wins under the rules: the rows, then the columns, then the
+    /// diagonals from the top left and top right.
+    pub fn winning_lines(&self, rules: Rules) -> Vec<Vec<u32>> {
+        let size = self.size;
+        let num = |row: usize, col: usize| self.nums[row * size + col].num;
+        let mut lines = vec![];
+        lines.extend((0..size).map(|row| (0..size).map(|col| num(row, col)).collect()));
+        lines.extend((0..size).map(|col| (0..size).map(|row| num(row, col)).collect()));
+        if rules.diagonals {
+            lines.push((0..size).map(|i| num(i, i)).collect());
+            lines.push((0..size).map(|i| num(i, size - 1 - i)).collect());
+        }
+        lines
+    }
+
+    pub fn is_bingo(&self, rules: Rules) -> bool {
+        self.has_full_row_or_col || (rules.diagonals && self.diagonal_hits.contains(&self.size))
+    }
+
+    pub fn sum_of_unmarked(&self) -> u32 {
+        self.sum_of_unmarked
+    }
+}
+
+/// Which lines of marked numbers win. Rows and columns always do.
+#[derive(Eq, PartialEq, Copy, Clone, Default, Debug)]
+pub struct Rules {
+    /// Whether the two corner to corner diagonals also win.
+    pub diagonals: bool,
+}
+
+/// A board winning, which each board does at most once.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Win {
+    /// The board's index among the game's boards.
+    pub board: usize,
+    /// How many numbers had been drawn when the board won, counting from 1.
+    pub turn: usize,
+    pub number: u32,
+    /// The sum of the board's unmarked numbers times the winning number.
+    pub score: u32,
+}
+
+/// A game of bingo in progress, with numbers drawn one at a time. Each draw only touches the cells
+/// holding the number drawn, found through an index of every board's numbers, so drawing costs the
+/// same however many boards there are.
+#[derive(Clone, Debug)]
+pub struct Game {
+    boards: Vec<Board>,
+    rules: Rules,
+    has_won: Vec<bool>,
+    turn: usize,
+    // Every (board, cell) holding each number, in board order
+    cells_by_number: HashMap<u32, Vec<(usize, usize)>>,
+}
+impl Game {
+    pub fn new(boards: Vec<Board>, rules: Rules) -> Game {
+        let mut cells_by_number: HashMap<u32, Vec<(usize, usize)>> = HashMap::new();
+        for (board_index, board) in boards.iter().enumerate() {
+            for (cell, number) in board.numbers().enumerate() {
+                cells_by_number.entry(number).or_default().push((board_index, cell));
+            }
+        }
+        let has_won = vec![false; boards.len()];
+        Game { boards, rules, has_won, turn: 0, cells_by_number }
+    }
+
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+
+    /// How many numbers have been drawn.
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+
+    /// Marks the number on every board that hasn't already won, returning the boards that won with
+    /// it in board order. Boards that have won are left as they were when they won.
+    pub fn draw(&mut self, number: u32) -> Vec<Win> {
+        self.turn += 1;
+        let Some(cells) = self.cells_by_number.get(&number) else {
+            return vec![];
+        };
+        let mut wins = vec![];
+        for (i, &(board_index, cell)) in cells.iter().enumerate() {
+            if self.has_won[board_index] {
+                continue;
+            }
+            let board = &mut self.boards[board_index];
+            board.mark(cell);
+            // A board may hold the number more than once, so it's only checked once all are marked
+            let is_last_cell_on_board = cells.get(i + 1).is_none_or(|&(next, _)| next != board_index);
+            if is_last_cell_on_board && board.is_bingo(self.rules) {
+                self.has_won[board_index] = true;
+                wins.push(Win { board: board_index, turn: self.turn, number, score: board.sum_of_unmarked() * number });
+            }
+        }
+        wins
+    }
+
+    /// Draws every number, returning each board's win in the order they won, with boards that won
+    /// on the same turn in board order. Boards that never win are left out.
+    pub fn play(&mut self, draws: &[u32]) -> Vec<Win> {
+        draws.iter().flat_map(|&number| self.draw(number)).collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Bingo {
+    pub draw_numbers: Vec<u32>,
+    pub boards: Vec<Board>,
+}
+impl Bingo {
+    /// Every board's win, in the order in which the boards win.
+    pub fn ranking(&self, rules: Rules) -> Vec<Win> {
+        Game::new(self.boards.clone(), rules).play(&self.draw_numbers)
+    }
+}
+
+// Parses a square board of whitespace separated numbers, one row per line
+fn parse_board(board_chunk: &str) -> ParseResult<Board> {
+    let rows = board_chunk.lines().map(|line| {
+        line.split_whitespace()
+            .map(|s| parse_at(s, "board number"))
+            .collect::<ParseResult<Vec<u32>>>()
+    }).collect::<ParseResult<Vec<_>>>()?;
+
+    let size = rows.len();
+    if size == 0 {
+        return Err(ParseError::at(board_chunk, "Expected a board"));
+    }
+    if let Some((line, row)) = board_chunk.lines().zip(&rows).find(|(_, row)| row.len() != size) {
+        return Err(ParseError::at(line, format!("Expected {} numbers in each row of a board with {} rows, found {}", size, size, row.len())));
+    }
+    Ok(Board::new(size, &rows.concat()))
+}
+
+/// Why a game has no first or last winner to score.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Unfinished {
+    NoWinner,
+    /// Some boards never won, so there's no board that won last.
+    NotEveryBoardWon { won: usize, boards: usize },
+}
+
+impl fmt::Display for Unfinished {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unfinished::NoWinner => write!(f, "No board won"),
+            Unfinished::NotEveryBoardWon { won, boards } => write!(f, "Only {} of {} boards won", won, boards),
+        }
+    }
+}
+
+impl std::error::Error for Unfinished {}
+
+pub struct Day04;
+impl Solution for Day04 {
+    type Input = Bingo;
+    type Answer1 = Result<u32, Unfinished>;
+    type Answer2 = Result<u32, Unfinished>;
+
+    fn parse(input: &str) -> ParseResult<Bingo> {
+        let mut chunks = input.split("\n\n");
+
+        let draw_numbers = chunks.next().unwrap_or_default()
+            .split(',')
+            .map(|s| parse_at(s.trim(), "drawn number"))
+            .collect::<ParseResult<Vec<u32>>>()?;
+
+        let boards = chunks.map(parse_board).collect::<ParseResult<Vec<Board>>>()?;
+
+        Ok(Bingo { draw_numbers, boards })
+    }
+
+    fn part1(bingo: &Bingo) -> Result<u32, Unfinished> {
+        bingo.ranking(Rules::default()).first().map(|win| win.score).ok_or(Unfinished::NoWinner)
+    }
+
+    fn part2(bingo: &Bingo) -> Result<u32, Unfinished> {
+        let ranking = bingo.ranking(Rules::default());
+        match ranking.last() {
+            None => Err(Unfinished::NoWinner),
+            Some(_) if ranking.len() < bingo.boards.len() => {
+                Err(Unfinished::NotEveryBoardWon { won: ranking.len(), boards: bingo.boards.len() })
+            },
+            Some(win) => Ok(win.score),
+        }
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Result<u32, Unfinished>> {
+    Ok(Day04::part1(&Day04::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<Result<u32, Unfinished>> {
+    Ok(Day04::part2(&Day04::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn test_ranking_of_example() {
+        let bingo = Day04::parse(EXAMPLE).unwrap();
+        let ranking = bingo.ranking(Rules::default());
+        let ranked = ranking.iter().map(|win| (win.board, win.turn, win.number, win.score)).collect::<Vec<_>>();
+        assert_eq!(ranked, [(2, 12, 24, 4512), (0, 14, 16, 2192), (1, 15, 13, 1924)]);
+    }
+
+    #[test]
+    fn test_diagonals_on_small_boards() {
+        let bingo = Day04::parse("5,1,9\n\n1 2 3\n4 5 6\n7 8 9\n\n1 2\n3 5").unwrap();
+        assert_eq!(bingo.boards.iter().map(Board::size).collect::<Vec<_>>(), [3, 2]);
+        assert_eq!(bingo.ranking(Rules::default()).len(), 0);
+        let ranking = bingo.ranking(Rules { diagonals: true });
+        let ranked = ranking.iter().map(|win| (win.board, win.turn)).collect::<Vec<_>>();
+        assert_eq!(ranked, [(1, 2), (0, 3)]);
+    }
+
+    #[test]
+    fn test_unfinished_games() {
+        let input = "1,2\n\n1 2\n3 4\n\n5 6\n7 8";
+        assert_eq!(part1(input), Ok(Ok(7 * 2)));
+        assert_eq!(part2(input), Ok(Err(Unfinished::NotEveryBoardWon { won: 1, boards: 2 })));
+        assert_eq!(part1("9\n\n1 2\n3 4"), Ok(Err(Unfinished::NoWinner)));
+    }
+
+    #[test]
+    fn test_boards_must_be_square() {
+        let input = "1,2\n\n1 2\n3 4 5";
+        let error = Day04::parse(input).unwrap_err().locate(input);
+        assert_eq!(error.location.map(|location| location.line), Some(4));
+    }
+
+    // Plays by marking and checking every line of every board on each draw
+    fn reference_ranking(bingo: &Bingo, rules: Rules) -> Vec<(usize, usize, u32)> {
+        let mut marked = bingo.boards.iter().map(|board| vec![false; board.size() * board.size()]).collect::<Vec<_>>();
+        let mut ranking = vec![];
+        for (turn, &number) in bingo.draw_numbers.iter().enumerate() {
+            for (index, board) in bingo.boards.iter().enumerate() {
+                if ranking.iter().any(|&(won, _, _)| won == index) {
+                    continue;
+                }
+                let numbers = board.numbers().collect::<Vec<_>>();
+                numbers.iter().enumerate().filter(|&(_, &n)| n == number).for_each(|(cell, _)| marked[index][cell] = true);
+                let size = board.size();
+                let is_marked = |row: usize, col: usize| marked[index][row * size + col];
+                let won = (0..size).any(|row| (0..size).all(|col| is_marked(row, col)))
+                    || (0..size).any(|col| (0..size).all(|row| is_marked(row, col)))
+                    || (rules.diagonals && (0..size).all(|i| is_marked(i, i)))
+                    || (rules.diagonals && (0..size).all(|i| is_marked(i, size - 1 - i)));
+                if won {
+                    let unmarked = numbers.iter().zip(&marked[index]).filter(|(_, &m)| !m).map(|(n, _)| n).sum::<u32>();
+                    ranking.push((index, turn + 1, unmarked * number));
+                }
+            }
+        }
+        ranking
+    }
+
+    #[test]
+    fn test_large_games_match_reference() {
+        // A simple linear congruential generator, so the game is the same every time
+        let mut seed = 12345u64;
+        let mut random = |below: u64| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) % below
+        };
+        let boards = (0..2000).map(|_| {
+            let size = 2 + random(6) as usize;
+            // Numbers may repeat on a board
+            let numbers = (0..size * size).map(|_| random(400) as u32).collect::<Vec<_>>();
+            Board::new(size, &numbers)
+        }).collect();
+        let draw_numbers = (0..600).map(|_| random(400) as u32).collect();
+        let bingo = Bingo { draw_numbers, boards };
+
+        for rules in [Rules::default(), Rules { diagonals: true }] {
+            let ranking = bingo.ranking(rules).iter().map(|win| (win.board, win.turn, win.score)).collect::<Vec<_>>();
+            assert_eq!(ranking, reference_ranking(&bingo, rules));
+        }
+    }
+}
@@ -1,107 +1,89 @@
-use std::fs;
+use std::process::ExitCode;
+use aoc_common::{input, solution, Solution};
+use day04::strategy::{self, Odds};
+use day04::{Bingo, Day04, Rules};
 
-#[derive(Copy, Clone)]
-struct BoardNum {
-    num: u32,
-    is_marked: bool
-}
-struct Board {
-    nums: [BoardNum; 25]
-}
-impl Board {
-    fn new(nums: &Vec<u32>) -> Board {
-        if nums.len() != 25 {
-            panic!("Wrong number of numbers for a board");
-        }
-        let mut nums_arr: [BoardNum; 25] = [BoardNum { num: 0, is_marked: false }; 25];
-        for (index, num) in nums.iter().enumerate() {
-            nums_arr[index] = BoardNum { num: *num, is_marked: false };
-        }
-        Board {
-            nums: nums_arr
-        }
-    }
+const USAGE: &str = "\
+Usage: day04 [ranking [--diagonals]]
+       day04 win-first <board> [--diagonals]
+       day04 odds [<seed>] [--diagonals]";
+// How many random games the odds are estimated from
+const ODDS_TRIALS: usize = 10_000;
+const DEFAULT_SEED: u64 = 2021;
 
-    fn mark_if_present(&mut self, number: u32) {
-        let indexes: Vec<usize> = self.nums.iter().enumerate()
-            .filter(|&(_, board_num)| board_num.num == number)
-            .map(|(index, _)| index)
-            .collect();
-        for index in indexes {
-            self.nums[index].is_marked = true;
-        }
+fn main() -> ExitCode {
+    let input = input::read_to_string("input");
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    // Every analysis can count diagonals as lines, given last
+    let diagonals = args.len() > 1 && args.last().is_some_and(|arg| arg == "--diagonals");
+    if diagonals {
+        args.pop();
     }
-
-    fn is_bingo(&self) -> bool {
-        // Rows
-        self.are_marked([ 0,  1,  2,  3,  4]) ||
-        self.are_marked([ 5,  6,  7,  8,  9]) ||
-        self.are_marked([10, 11, 12, 13, 14]) ||
-        self.are_marked([15, 16, 17, 18, 19]) ||
-        self.are_marked([20, 21, 22, 23, 24]) ||
-        // Cols
-        self.are_marked([ 0,  5, 10, 15, 20]) ||
-        self.are_marked([ 1,  6, 11, 16, 21]) ||
-        self.are_marked([ 2,  7, 12, 17, 22]) ||
-        self.are_marked([ 3,  8, 13, 18, 23]) ||
-        self.are_marked([ 4,  9, 14, 19, 24])
+    let rules = Rules { diagonals };
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => solution::print_answers::<Day04>(&input),
+        ["ranking"] => print_ranking(&input, rules),
+        ["win-first", board] => match board.parse::<usize>() {
+            Ok(board) if board > 0 => print_win_first(&input, board - 1, rules),
+            _ => usage(),
+        },
+        ["odds"] => print_odds(&input, DEFAULT_SEED, rules),
+        ["odds", seed] => match seed.parse() {
+            Ok(seed) => print_odds(&input, seed, rules),
+            Err(_) => usage(),
+        },
+        _ => usage(),
     }
+}
 
-    fn is_marked(&self, index: usize) -> bool {
-        self.nums[index].is_marked
-    }
+fn usage() -> ExitCode {
+    eprintln!("{}", USAGE);
+    ExitCode::from(2)
+}
 
-    fn are_marked(&self, indexes: [usize; 5]) -> bool {
-        self.is_marked(indexes[0]) &&
-        self.is_marked(indexes[1]) &&
-        self.is_marked(indexes[2]) &&
-        self.is_marked(indexes[3]) &&
-        self.is_marked(indexes[4])
-    }
+fn parse(input: &str) -> Option<Bingo> {
+    Day04::parse(input).map_err(|e| eprintln!("Invalid input: {}", e.locate(input))).ok()
+}
 
-    fn sum_of_unmarked(&self) -> u32 {
-        self.nums.iter()
-            .filter(|bn| !bn.is_marked)
-            .map(|bn| bn.num)
-            .sum()
+// Prints every board that wins, from first to last
+fn print_ranking(input: &str, rules: Rules) -> ExitCode {
+    let Some(bingo) = parse(input) else {
+        return ExitCode::FAILURE;
+    };
+    let ranking = bingo.ranking(rules);
+    println!("{:>4} {:>6} {:>6} {:>6} {:>8}", "Rank", "Board", "Turn", "Number", "Score");
+    for (rank, win) in ranking.iter().enumerate() {
+        println!("{:>4} {:>6} {:>6} {:>6} {:>8}", rank + 1, win.board + 1, win.turn, win.number, win.score);
     }
+    println!("{} of {} boards won", ranking.len(), bingo.boards.len());
+    ExitCode::SUCCESS
 }
 
-fn main() {
-    let contents = fs::read_to_string("input")
-        .expect("Something went wrong reading the file");
-    let mut chunks = contents.split("\n\n");
-
-    let draw_numbers: Vec<u32> = chunks.next().expect("Could not find first chunk")
-        .split(",")
-        .map(|s| s.parse().expect("Could not parse number"))
-        .collect();
-
-    let num_re = regex::Regex::new(r"\d+").unwrap();
-
-    let mut boards: Vec<Board> = chunks.map(|board_chunk| {
-        let nums: Vec<u32> = num_re.find_iter(board_chunk)
-            .filter_map(|m| m.as_str().parse().ok())
-            .collect();
-        Board::new(&nums)
-    }).collect();
+// Prints the fewest numbers to draw for a board (counting from 1) to win before any other
+fn print_win_first(input: &str, board: usize, rules: Rules) -> ExitCode {
+    let Some(bingo) = parse(input) else {
+        return ExitCode::FAILURE;
+    };
+    if board >= bingo.boards.len() {
+        eprintln!("There are only {} boards", bingo.boards.len());
+        return ExitCode::FAILURE;
+    }
+    match strategy::shortest_win_first(&bingo.boards, board, rules) {
+        Some(draws) => println!("{}", draws.iter().map(u32::to_string).collect::<Vec<_>>().join(",")),
+        None => println!("Board {} can't win before every other board", board + 1),
+    }
+    ExitCode::SUCCESS
+}
 
-    let mut winning_board_count = 0;
-    let num_boards = boards.len();
-    'outer: for drawn_num in draw_numbers {
-        for board in boards.iter_mut() {
-            if !board.is_bingo() {
-                board.mark_if_present(drawn_num);
-                if board.is_bingo() {
-                    winning_board_count += 1;
-                    if winning_board_count == 1 {
-                        println!("Part 1: {}", board.sum_of_unmarked() * drawn_num);
-                    } else if winning_board_count == num_boards {
-                        println!("Part 2: {}", board.sum_of_unmarked() * drawn_num);
-                        break 'outer;
-                    }
-                }
-            }
-        }
+fn print_odds(input: &str, seed: u64, rules: Rules) -> ExitCode {
+    let Some(bingo) = parse(input) else {
+        return ExitCode::FAILURE;
+    };
+    let odds = strategy::win_odds(&bingo, rules, ODDS_TRIALS, seed);
+    println!("{:>6} {:>8} {:>8}", "Board", "First", "Last");
+    for (board, Odds { first, last }) in odds.iter().enumerate() {
+        println!("{:>6} {:>7.2}% {:>7.2}%", board + 1, first * 100.0, last * 100.0);
     }
+    println!("From {} games with seed {}", ODDS_TRIALS, seed);
+    ExitCode::SUCCESS
 }
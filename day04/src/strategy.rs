@@ -0,0 +1,127 @@
+//! What-if analysis of a bingo game: how to draw numbers so that a chosen board wins first, and how
+//! likely each board is to win first or last when the numbers are drawn at random.
+
+use std::collections::HashSet;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use crate::{Bingo, Board, Game, Rules};
+
+/// A shortest sequence of numbers whose drawing makes the `target` board win before any other,
+/// without another board winning on the same draw, or `None` if that can't happen.
+///
+/// The target wins once every number in one of its lines has been drawn, so the shortest sequences
+/// are the numbers of its lines with the fewest distinct numbers, which no other board can complete
+/// a line from. Those can be drawn in any order.
+pub fn shortest_win_first(boards: &[Board], target: usize, rules: Rules) -> Option<Vec<u32>> {
+    let other_lines = boards.iter().enumerate()
+        .filter(|&(index, _)| index != target)
+        .flat_map(|(_, board)| board.winning_lines(rules))
+        .collect::<Vec<_>>();
+
+    let mut candidates = boards[target].winning_lines(rules).into_iter().map(|line| {
+        let mut seen = HashSet::new();
+        line.into_iter().filter(|&number| seen.insert(number)).collect::<Vec<_>>()
+    }).collect::<Vec<_>>();
+    // Stable, so the first of the shortest lines is preferred
+    candidates.sort_by_key(Vec::len);
+    candidates.into_iter().find(|draws| {
+        let drawn = draws.iter().collect::<HashSet<_>>();
+        !other_lines.iter().any(|line| line.iter().all(|number| drawn.contains(number)))
+    })
+}
+
+/// The chances of a board finishing first or last, as fractions of the games played.
+#[derive(PartialEq, Copy, Clone, Default, Debug)]
+pub struct Odds {
+    /// How often the board was among those winning on the first turn anyone won.
+    pub first: f64,
+    /// How often the board was among those winning on the last turn anyone won, in games where every
+    /// board won.
+    pub last: f64,
+}
+
+/// Estimates each board's odds by playing `trials` games, each with the puzzle's numbers drawn in a
+/// random order. The same `seed` always gives the same odds.
+pub fn win_odds(bingo: &Bingo, rules: Rules, trials: usize, seed: u64) -> Vec<Odds> {
+    assert!(trials > 0, "Must play at least one trial");
+    let mut rng = StdRng::seed_from_u64(seed);
+    // Cloning a new game is cheaper than indexing the boards' numbers again
+    let new_game = Game::new(bingo.boards.clone(), rules);
+    let mut draws = bingo.draw_numbers.clone();
+    let mut firsts = vec![0; bingo.boards.len()];
+    let mut lasts = vec![0; bingo.boards.len()];
+
+    for _ in 0..trials {
+        draws.shuffle(&mut rng);
+        let ranking = new_game.clone().play(&draws);
+        let (Some(first), Some(last)) = (ranking.first(), ranking.last()) else {
+            continue;
+        };
+        for win in ranking.iter().take_while(|win| win.turn == first.turn) {
+            firsts[win.board] += 1;
+        }
+        if ranking.len() == bingo.boards.len() {
+            for win in ranking.iter().rev().take_while(|win| win.turn == last.turn) {
+                lasts[win.board] += 1;
+            }
+        }
+    }
+
+    firsts.into_iter().zip(lasts).map(|(first, last)| Odds {
+        first: first as f64 / trials as f64,
+        last: last as f64 / trials as f64,
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortest_win_first() {
+        // Board 0's shortest lines are its top two rows, but drawing 1 and 2 would also complete
+        // board 1's top row, so its second row is the one to draw
+        let boards = [
+            Board::new(3, &[1, 2, 1, 5, 3, 3, 6, 7, 8]),
+            Board::new(2, &[1, 2, 9, 10]),
+        ];
+        assert_eq!(shortest_win_first(&boards, 0, Rules::default()), Some(vec![5, 3]));
+        assert_eq!(shortest_win_first(&boards, 1, Rules::default()), Some(vec![9, 10]));
+
+        // A board whose every line is also a line of another can't win alone
+        let boards = [Board::new(2, &[1, 2, 3, 4]), Board::new(2, &[4, 3, 2, 1])];
+        assert_eq!(shortest_win_first(&boards, 0, Rules::default()), None);
+    }
+
+    #[test]
+    fn test_shortest_draws_win_first_when_played() {
+        let boards = (0..20u32).map(|i| {
+            Board::new(4, &(0..16).map(|j| (i * 7 + j * 5) % 40).collect::<Vec<_>>())
+        }).collect::<Vec<_>>();
+        for rules in [Rules::default(), Rules { diagonals: true }] {
+            for target in 0..boards.len() {
+                if let Some(draws) = shortest_win_first(&boards, target, rules) {
+                    let ranking = Game::new(boards.clone(), rules).play(&draws);
+                    assert_eq!(ranking.iter().map(|win| (win.board, win.turn)).collect::<Vec<_>>(), [(target, draws.len())]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_win_odds_are_seeded() {
+        let bingo = Bingo {
+            draw_numbers: (1..=12).collect(),
+            boards: vec![Board::new(2, &[1, 2, 3, 4]), Board::new(2, &[5, 6, 7, 8]), Board::new(2, &[9, 10, 11, 12])],
+        };
+        let odds = win_odds(&bingo, Rules::default(), 500, 7);
+        assert_eq!(odds, win_odds(&bingo, Rules::default(), 500, 7));
+        assert!(odds.iter().all(|odds| odds.first > 0.0 && odds.last > 0.0));
+        // Boards can only share first or last place by winning on the same draw, which boards with no
+        // numbers in common can't
+        let total = |place: fn(&Odds) -> f64| odds.iter().map(place).sum::<f64>();
+        assert!((total(|odds| odds.first) - 1.0).abs() < 1e-9);
+        assert!((total(|odds| odds.last) - 1.0).abs() < 1e-9);
+    }
+}
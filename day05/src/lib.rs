@@ -0,0 +1,368 @@
+use std::collections::HashMap;
+use std::iter;
+use aoc_common::coord::Coord;
+use aoc_common::parse::parse_at;
+use aoc_common::render::{Animation, Frame};
+use aoc_common::{trace, ParseError, ParseResult, Solution};
+
+pub mod sweep;
+
+pub type Line = (Coord, Coord);
+
+/// The furthest a coordinate may be from 0, which leaves room for the sums and differences of
+/// coordinates, and twice those, that drawing and sweeping lines work out. Every function taking
+/// lines expects them to be within it, as parsed lines are.
+pub const MAX_COORDINATE: isize = isize::MAX / 4;
+
+/// Which lines of vents are allowed, and so how overlaps are counted.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Mode {
+    /// Only horizontal, vertical and 45 degree lines, as in the puzzle, whose overlaps can be
+    /// counted without visiting every position along them (see `sweep`).
+    Puzzle,
+    /// Lines at any angle, drawn a position at a time with Bresenham's algorithm, which draws
+    /// puzzle lines exactly as the puzzle does.
+    General,
+}
+
+pub fn parse_lines(input: &str, mode: Mode) -> ParseResult<Vec<Line>> {
+    let num_re = regex::Regex::new(r"^(-?\d+),(-?\d+) -> (-?\d+),(-?\d+)$").unwrap();
+    input.lines().map(|line| {
+        let captures = num_re.captures(line)
+            .ok_or_else(|| ParseError::at(line, "Expected a line like 'x1,y1 -> x2,y2'"))?;
+        let value = |index| {
+            let text = captures.get(index).unwrap().as_str();
+            match parse_at::<isize>(text, "coordinate")? {
+                value if value.unsigned_abs() <= MAX_COORDINATE.unsigned_abs() => Ok(value),
+                _ => Err(ParseError::at(text, format!("Expected a coordinate between -{0} and {0}", MAX_COORDINATE))),
+            }
+        };
+        let (from, to) = (Coord::new(value(1)?, value(2)?), Coord::new(value(3)?, value(4)?));
+        let (dx, dy) = (to.x - from.x, to.y - from.y);
+        if mode == Mode::Puzzle && dx != 0 && dy != 0 && dx.abs() != dy.abs() {
+            return Err(ParseError::at(line, "Expected a horizontal, vertical or 45 degree line (other angles need general mode)"));
+        }
+        Ok((from, to))
+    }).collect()
+}
+
+pub struct Day05;
+impl Solution for Day05 {
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> ParseResult<Vec<Line>> {
+        parse_lines(input, Mode::Puzzle)
+    }
+
+    fn part1(lines: &Vec<Line>) -> usize {
+        count_overlaps(lines, false, Mode::Puzzle)
+    }
+
+    fn part2(lines: &Vec<Line>) -> usize {
+        count_overlaps(lines, true, Mode::Puzzle)
+    }
+}
+
+/// The puzzle in general mode, where lines may be at any angle.
+pub struct Day05General;
+impl Solution for Day05General {
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> ParseResult<Vec<Line>> {
+        parse_lines(input, Mode::General)
+    }
+
+    fn part1(lines: &Vec<Line>) -> usize {
+        count_overlaps(lines, false, Mode::General)
+    }
+
+    fn part2(lines: &Vec<Line>) -> usize {
+        count_overlaps(lines, true, Mode::General)
+    }
+}
+
+// A heatmap of every line's vents, which is the same in either mode for lines the puzzle allows
+impl Animation for Day05 {
+    const PALETTE: &'static str = "heat";
+
+    fn frames(lines: &Vec<Line>) -> Vec<Frame> {
+        Day05General::frames(lines)
+    }
+}
+
+impl Animation for Day05General {
+    const PALETTE: &'static str = "heat";
+
+    fn frames(lines: &Vec<Line>) -> Vec<Frame> {
+        vec![heatmap(lines)]
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<usize> {
+    Ok(Day05::part1(&Day05::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<usize> {
+    Ok(Day05::part2(&Day05::parse(input)?))
+}
+
+/// How many positions have two or more vents. Diagonal lines, at whatever angle, are only included
+/// if `include_diagonals` is set. In puzzle mode, lines at angles the puzzle doesn't allow are
+/// still counted, a position at a time as in general mode.
+pub fn count_overlaps(coord_pairs: &[Line], include_diagonals: bool, mode: Mode) -> usize {
+    let non_diag_pairs: Vec<_> = coord_pairs.iter()
+        .filter(|(a, b)| a.x == b.x || a.y == b.y)
+        .collect();
+    let diag_pairs: Vec<_> = coord_pairs.iter()
+        .filter(|(a, b)| a.x != b.x && a.y != b.y)
+        .collect();
+
+    let mut pairs = non_diag_pairs;
+    if include_diagonals {
+        pairs.extend(diag_pairs);
+    }
+
+    if trace::is_enabled() {
+        // The field is drawn from the origin, so it's measured from there, and by the lines' ends
+        // alone so that large fields aren't drawn only to be skipped
+        let ends = pairs.iter().flat_map(|&&(from, to)| [from, to]).chain([Coord::new(0, 0)]);
+        let (xs, ys): (Vec<_>, Vec<_>) = ends.map(|end| (end.x, end.y)).unzip();
+        let size = |values: &[isize]| values.iter().max().unwrap() - values.iter().min().unwrap() + 1;
+        if size(&xs) <= MAX_FIELD_SIZE && size(&ys) <= MAX_FIELD_SIZE {
+            let mut vent_map = VentMap::default();
+            vent_map.record_vents(&pairs);
+            trace!("field", "vent counts:\n{}", vent_map.field_string());
+        } else {
+            trace!("field", "field is {}x{}, too large to draw", size(&xs), size(&ys));
+        }
+    }
+    let swept = match mode {
+        Mode::Puzzle => sweep::count_overlapping(&pairs, 2).ok(),
+        Mode::General => None,
+    };
+    swept.unwrap_or_else(|| {
+        let mut vent_map = VentMap::default();
+        vent_map.record_vents(&pairs);
+        vent_map.count_overlapping(2)
+    })
+}
+
+/// The positions along a line from `from` to `to` inclusive, at any angle, as drawn by Bresenham's
+/// line algorithm. Horizontal, vertical and 45 degree lines take every position along them.
+pub fn bresenham(from: Coord, to: Coord) -> impl Iterator<Item = Coord> {
+    let (dx, dy) = ((to.x - from.x).abs(), -(to.y - from.y).abs());
+    let (step_x, step_y) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+    // How far the drawn line is from the true one, scaled to stay an integer
+    let mut error = dx + dy;
+    let mut position = Some(from);
+    iter::from_fn(move || {
+        let current = position?;
+        position = (current != to).then(|| {
+            let mut next = current;
+            let doubled_error = 2 * error;
+            if doubled_error >= dy {
+                error += dy;
+                next.x += step_x;
+            }
+            if doubled_error <= dx {
+                error += dx;
+                next.y += step_y;
+            }
+            next
+        });
+        Some(current)
+    })
+}
+
+// The most positions along either side of a field that's traced
+const MAX_FIELD_SIZE: isize = 1000;
+
+/// The most pixels a heatmap has along either side.
+pub const MAX_HEATMAP_SIZE: usize = 1000;
+
+/// Draws the smallest rectangle holding every line, with each position's level being how many
+/// vents it has, up to the most a frame can show. Rectangles wider or taller than
+/// `MAX_HEATMAP_SIZE` are scaled down to fit, with each line drawn straight across the pixels, so
+/// each pixel shows how many lines pass through it, however long they are.
+pub fn heatmap(lines: &[Line]) -> Frame {
+    let ends = lines.iter().flat_map(|&(from, to)| [from, to]);
+    let (Some(left), Some(top)) = (ends.clone().map(|end| end.x).min(), ends.clone().map(|end| end.y).min()) else {
+        return Frame::new(1, 1, 2);
+    };
+    let (right, bottom) = (ends.clone().map(|end| end.x).max().unwrap(), ends.map(|end| end.y).max().unwrap());
+    let (width, height) = ((right - left + 1) as usize, (bottom - top + 1) as usize);
+    // How many positions each pixel covers along each side, the same both ways to keep the shape
+    let scale = width.max(height).div_ceil(MAX_HEATMAP_SIZE);
+    let (frame_width, frame_height) = (width.div_ceil(scale), height.div_ceil(scale));
+    let pixel = |position: Coord| Coord::new((position.x - left) / scale as isize, (position.y - top) / scale as isize);
+
+    // Bresenham's lines visit each pixel at most once, so a line is only counted once per pixel
+    let mut counts = vec![0u32; frame_width * frame_height];
+    for &(from, to) in lines {
+        for position in bresenham(pixel(from), pixel(to)) {
+            counts[position.y as usize * frame_width + position.x as usize] += 1;
+        }
+    }
+    let most = counts.iter().max().copied().unwrap_or(0);
+    let levels = (most + 1).clamp(2, u8::MAX as u32) as u8;
+    let mut frame = Frame::new(frame_width, frame_height, levels);
+    for (i, &count) in counts.iter().enumerate() {
+        frame.set(i % frame_width, i / frame_width, count.min(levels as u32 - 1) as u8);
+    }
+    frame
+}
+
+/// How many vents there are at each position covered by any, however far apart or negative the
+/// positions are. Each line is recorded a position at a time, so this is much slower than counting
+/// overlaps with `sweep` for long lines, but it's simple enough to check that against.
+#[derive(Default, Debug)]
+pub struct VentMap {
+    counts: HashMap<Coord, u32>,
+}
+impl VentMap {
+    pub fn record_vents(&mut self, pairs: &[&Line]) {
+        for &&(from, to) in pairs {
+            trace!("line", "({},{}) -> ({},{})", from.x, from.y, to.x, to.y);
+            for position in bresenham(from, to) {
+                trace!("vent", "{}x{}", position.x, position.y);
+                *self.counts.entry(position).or_insert(0) += 1;
+            }
+        }
+    }
+
+    pub fn count(&self, position: Coord) -> u32 {
+        self.counts.get(&position).copied().unwrap_or(0)
+    }
+
+    /// How many positions have at least `min_vents` vents.
+    pub fn count_overlapping(&self, min_vents: u32) -> usize {
+        self.counts.values().filter(|&&count| count >= min_vents).count()
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle holding every vent, or
+    /// `None` if there aren't any.
+    pub fn bounds(&self) -> Option<(Coord, Coord)> {
+        let xs = self.counts.keys().map(|position| position.x);
+        let ys = self.counts.keys().map(|position| position.y);
+        Some((Coord::new(xs.clone().min()?, ys.clone().min()?), Coord::new(xs.max()?, ys.max()?)))
+    }
+
+    // Draws the field from the origin (or the furthest vent up or left of it) as far as the
+    // furthest vent, as in the puzzle description
+    fn field_string(&self) -> String {
+        let Some((top_left, bottom_right)) = self.bounds() else {
+            return ".\n".to_string();
+        };
+        let mut result = String::new();
+        for y in top_left.y.min(0)..=bottom_right.y {
+            for x in top_left.x.min(0)..=bottom_right.x {
+                match self.count(Coord::new(x, y)) {
+                    0 => result.push('.'),
+                    count => result.push_str(&count.to_string()),
+                }
+            }
+            result.push('\n');
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input;
+    use proptest::prelude::*;
+    use super::*;
+
+    #[test]
+    fn test_vents_anywhere() {
+        let input = "-5,-5 -> 5,5\n5,-5 -> -5,5\n2000,0 -> 3000,0\n2500,0 -> 2500,0\n0,0 -> 0,1";
+        assert_eq!(part1(input), Ok(1));
+        assert_eq!(part2(input), Ok(2));
+    }
+
+    #[test]
+    fn test_extreme_coordinates() {
+        let input = "-9223372036854775808,0 -> 9223372036854775807,0";
+        let error = Day05::parse(input).unwrap_err().locate(input);
+        assert_eq!(error.text.as_deref(), Some("-9223372036854775808"));
+
+        let m = MAX_COORDINATE;
+        let input = format!("{0},0 -> -{0},0\n0,-{0} -> 0,{0}\n-{0},-{0} -> {0},{0}\n{0},-{0} -> -{0},{0}", m);
+        assert_eq!(part1(&input), Ok(1));
+        assert_eq!(part2(&input), Ok(1));
+        let ends = bresenham(Coord::new(-m, -m), Coord::new(m, m - 1)).take(2).collect::<Vec<_>>();
+        assert_eq!(ends, [Coord::new(-m, -m), Coord::new(-m + 1, -m + 1)]);
+    }
+
+    #[test]
+    fn test_counts_are_wide() {
+        let line = (Coord::new(0, 0), Coord::new(0, 9));
+        let mut vent_map = VentMap::default();
+        vent_map.record_vents(&vec![&line; 300]);
+        assert_eq!(vent_map.count(Coord::new(0, 4)), 300);
+        assert_eq!(vent_map.count_overlapping(300), 10);
+        assert_eq!(vent_map.bounds(), Some(line));
+    }
+
+    #[test]
+    fn test_any_angle_in_general_mode() {
+        let input = "0,0 -> 6,2\n0,2 -> 6,0\n3,0 -> 3,2";
+        assert!(Day05::parse(input).is_err());
+        let lines = Day05General::parse(input).unwrap();
+        let positions = |(from, to): Line| bresenham(from, to).map(|c| (c.x, c.y)).collect::<Vec<_>>();
+        assert_eq!(positions(lines[0]), [(0, 0), (1, 0), (2, 1), (3, 1), (4, 1), (5, 2), (6, 2)]);
+        assert_eq!(positions(lines[1]), [(0, 2), (1, 2), (2, 1), (3, 1), (4, 1), (5, 0), (6, 0)]);
+        // The shallow lines overlap from 2,1 to 4,1, and the vertical line crosses them at 3,1
+        assert_eq!(Day05General::part1(&lines), 0);
+        assert_eq!(Day05General::part2(&lines), 3);
+        // Lines the sweep can't count are drawn instead
+        assert!(sweep::count_overlapping(&lines.iter().collect::<Vec<_>>(), 2).is_err());
+        assert_eq!(count_overlaps(&lines, true, Mode::Puzzle), 3);
+
+        let heatmap = Day05General::frames(&lines).remove(0);
+        assert_eq!((heatmap.width(), heatmap.height(), heatmap.levels()), (7, 3, 4));
+        assert_eq!(heatmap.get(3, 1), 3);
+    }
+
+    #[test]
+    fn test_heatmap_is_scaled_down() {
+        let input = "0,0 -> 99999,0\n0,0 -> 0,1999";
+        let heatmap = Day05General::frames(&Day05General::parse(input).unwrap()).remove(0);
+        assert_eq!((heatmap.width(), heatmap.height()), (MAX_HEATMAP_SIZE, 20));
+        assert_eq!([heatmap.get(0, 0), heatmap.get(1, 0), heatmap.get(0, 1), heatmap.get(1, 1)], [2, 1, 1, 0]);
+
+        // Only the frame is drawn, however long the lines
+        let heatmap = Day05::frames(&Day05::parse("0,0 -> 3000000000,0\n0,0 -> 3000000000,3000000000").unwrap()).remove(0);
+        assert_eq!((heatmap.width(), heatmap.height()), (MAX_HEATMAP_SIZE, MAX_HEATMAP_SIZE));
+        assert_eq!([heatmap.get(0, 0), heatmap.get(999, 0), heatmap.get(500, 500), heatmap.get(1, 0)], [2, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_bresenham_matches_puzzle_lines() {
+        let lines = Day05::parse(&input::read_to_string("input")).unwrap();
+        assert_eq!(count_overlaps(&lines, true, Mode::General), count_overlaps(&lines, true, Mode::Puzzle));
+    }
+
+    #[test]
+    fn test_long_lines() {
+        let input = "0,0 -> 1000000000,0\n500000000,0 -> 1500000000,0\n0,-5 -> 10,5\n10,-5 -> 0,5";
+        assert_eq!(part1(input), Ok(500_000_001));
+        // The diagonals cross each other, and the first horizontal line, at 5,0
+        assert_eq!(part2(input), Ok(500_000_002));
+    }
+
+    proptest! {
+        #[test]
+        fn test_sweep_matches_raster(input in aoc_gen::day05(), min_vents in 1u32..4) {
+            let lines = Day05::parse(&input).unwrap();
+            let lines = lines.iter().collect::<Vec<_>>();
+            let mut vent_map = VentMap::default();
+            vent_map.record_vents(&lines);
+            prop_assert_eq!(sweep::count_overlapping(&lines, min_vents), Ok(vent_map.count_overlapping(min_vents)));
+        }
+    }
+}
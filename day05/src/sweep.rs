@@ -0,0 +1,223 @@
+//! Counting overlapping vents without visiting every position along each line, so the cost depends
+//! on how many lines there are and how often they cross, not on how long they are.
+//!
+//! Every horizontal, vertical or 45 degree line lies along one of four families of parallel lines.
+//! Within a family, lines along the same parallel are merged into runs, each covered by the same
+//! number of vents throughout, by sorting their ends. A position covered by runs from more than one
+//! family is where runs cross, which is found by sweeping across each pair of families.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use aoc_common::coord::Coord;
+use crate::Line;
+
+/// A line that isn't horizontal, vertical or at 45 degrees, so isn't along any family.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct OtherAngle(pub Line);
+
+impl fmt::Display for OtherAngle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (from, to) = self.0;
+        write!(f, "({},{}) -> ({},{}) isn't horizontal, vertical or at 45 degrees", from.x, from.y, to.x, to.y)
+    }
+}
+
+impl std::error::Error for OtherAngle {}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+enum Family {
+    /// Lines along which y is constant.
+    Horizontal,
+    /// x is constant.
+    Vertical,
+    /// x - y is constant.
+    Diagonal,
+    /// x + y is constant.
+    AntiDiagonal,
+}
+
+const FAMILIES: [Family; 4] = [Family::Horizontal, Family::Vertical, Family::Diagonal, Family::AntiDiagonal];
+
+impl Family {
+    // A single position is treated as a horizontal line
+    fn of(&(from, to): &Line) -> Result<Family, OtherAngle> {
+        match (to.x - from.x, to.y - from.y) {
+            (_, 0) => Ok(Family::Horizontal),
+            (0, _) => Ok(Family::Vertical),
+            (dx, dy) if dx == dy => Ok(Family::Diagonal),
+            (dx, dy) if dx == -dy => Ok(Family::AntiDiagonal),
+            _ => Err(OtherAngle((from, to))),
+        }
+    }
+
+    // The multiples of x and y whose sum is the same all along each of the family's lines
+    fn coefficients(self) -> (isize, isize) {
+        match self {
+            Family::Horizontal => (0, 1),
+            Family::Vertical => (1, 0),
+            Family::Diagonal => (1, -1),
+            Family::AntiDiagonal => (1, 1),
+        }
+    }
+
+    // Which of the family's lines a position lies on
+    fn key(self, position: Coord) -> isize {
+        let (a, b) = self.coefficients();
+        a * position.x + b * position.y
+    }
+
+    // How far along its line a position is: its y for vertical lines, and its x for the rest
+    fn offset(self, position: Coord) -> isize {
+        if self == Family::Vertical { position.y } else { position.x }
+    }
+
+    fn position(self, key: isize, offset: isize) -> Coord {
+        match self {
+            Family::Horizontal => Coord::new(offset, key),
+            Family::Vertical => Coord::new(key, offset),
+            Family::Diagonal => Coord::new(offset, offset - key),
+            Family::AntiDiagonal => Coord::new(offset, key - offset),
+        }
+    }
+}
+
+// The position on both the line `key` of one family and the line `other_key` of another, if they
+// cross at a position rather than between positions
+fn crossing(family: Family, key: isize, other: Family, other_key: isize) -> Option<Coord> {
+    let ((a, b), (c, d)) = (family.coefficients(), other.coefficients());
+    let determinant = a * d - b * c;
+    let x = key * d - b * other_key;
+    let y = a * other_key - key * c;
+    (x % determinant == 0 && y % determinant == 0).then(|| Coord::new(x / determinant, y / determinant))
+}
+
+/// Consecutive positions along one line of a family, all covered by the same number of vents.
+#[derive(Copy, Clone, Debug)]
+struct Run {
+    family: Family,
+    key: isize,
+    from: isize,
+    to: isize,
+    vents: u32,
+}
+impl Run {
+    fn len(&self) -> usize {
+        (self.to - self.from + 1) as usize
+    }
+
+    // The range of keys of another family's lines that this run crosses
+    fn keys_crossed(&self, other: Family) -> (isize, isize) {
+        let from = other.key(self.family.position(self.key, self.from));
+        let to = other.key(self.family.position(self.key, self.to));
+        (from.min(to), from.max(to))
+    }
+}
+
+// Merges the family's lines into runs
+fn runs(lines: &[&Line], family: Family) -> Vec<Run> {
+    // How the number of vents changes at each offset along each of the family's lines
+    let mut changes: BTreeMap<(isize, isize), i64> = BTreeMap::new();
+    for &&(start, end) in lines {
+        let key = family.key(start);
+        let (from, to) = (family.offset(start).min(family.offset(end)), family.offset(start).max(family.offset(end)));
+        *changes.entry((key, from)).or_insert(0) += 1;
+        *changes.entry((key, to + 1)).or_insert(0) -= 1;
+    }
+
+    let mut runs = vec![];
+    let mut vents = 0;
+    let mut last: Option<(isize, isize)> = None;
+    for (&(key, offset), &change) in &changes {
+        if let Some((last_key, last_offset)) = last {
+            // Each line starts and ends on the same parallel, so the count is back to 0 between them
+            if vents > 0 && last_key == key {
+                runs.push(Run { family, key, from: last_offset, to: offset - 1, vents: vents as u32 });
+            }
+        }
+        vents += change;
+        last = Some((key, offset));
+    }
+    runs
+}
+
+/// How many positions are covered by at least `min_vents` of the lines, or the first line at
+/// another angle than horizontal, vertical or 45 degrees, which can't be counted this way.
+pub fn count_overlapping(lines: &[&Line], min_vents: u32) -> Result<usize, OtherAngle> {
+    let families = lines.iter().map(|line| Family::of(line)).collect::<Result<Vec<_>, _>>()?;
+    let runs = FAMILIES.map(|family| {
+        let family_lines = lines.iter().zip(&families)
+            .filter(|&(_, &line_family)| line_family == family)
+            .map(|(&line, _)| line)
+            .collect::<Vec<_>>();
+        runs(&family_lines, family)
+    });
+
+    // The vents from each family at every position where runs of different families cross
+    let mut crossings: HashMap<Coord, [u32; 4]> = HashMap::new();
+    for (i, j) in [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)] {
+        for (run, other) in sweep_crossings(&runs[i], &runs[j]) {
+            let vents = crossings.entry(crossing(run.family, run.key, other.family, other.key).expect("Runs cross at a position")).or_default();
+            vents[i] = run.vents;
+            vents[j] = other.vents;
+        }
+    }
+
+    // Each position is counted once for each run over it with enough vents alone, so crossings are
+    // then counted by their total instead
+    let along_runs = runs.iter().flatten().filter(|run| run.vents >= min_vents).map(Run::len).sum::<usize>();
+    let corrections = crossings.values().map(|vents| {
+        let total = vents.iter().sum::<u32>();
+        (total >= min_vents) as isize - vents.iter().filter(|&&v| v >= min_vents).count() as isize
+    }).sum::<isize>();
+    Ok((along_runs as isize + corrections) as usize)
+}
+
+// Every pair of runs, one from each family, that cross at a position. Runs of the first family are
+// laid out along the keys of the second that they cross, and the second family's runs, each on a
+// single one of its keys, are swept across them, looking up the first family's runs that are in range.
+fn sweep_crossings<'a>(runs: &'a [Run], others: &'a [Run]) -> Vec<(&'a Run, &'a Run)> {
+    let Some(other_family) = others.first().map(|run| run.family) else {
+        return vec![];
+    };
+    let Some(family) = runs.first().map(|run| run.family) else {
+        return vec![];
+    };
+
+    enum Event<'a> {
+        Start(&'a Run),
+        Cross(&'a Run),
+        End(&'a Run),
+    }
+    let mut events = vec![];
+    for run in runs {
+        let (from, to) = run.keys_crossed(other_family);
+        events.push((from, 0, Event::Start(run)));
+        events.push((to, 2, Event::End(run)));
+    }
+    for other in others {
+        events.push((other.key, 1, Event::Cross(other)));
+    }
+    events.sort_by_key(|&(key, order, _)| (key, order));
+
+    // The runs of the first family in range, by their own key. Runs on the same key don't overlap,
+    // so there's only ever one in range for each key
+    let mut active: BTreeMap<isize, &Run> = BTreeMap::new();
+    let mut pairs = vec![];
+    for (_, _, event) in events {
+        match event {
+            Event::Start(run) => {
+                active.insert(run.key, run);
+            },
+            Event::End(run) => {
+                active.remove(&run.key);
+            },
+            Event::Cross(other) => {
+                let (from, to) = other.keys_crossed(family);
+                pairs.extend(active.range(from..=to)
+                    .filter(|&(&key, _)| crossing(family, key, other_family, other.key).is_some())
+                    .map(|(_, &run)| (run, other)));
+            },
+        }
+    }
+    pairs
+}
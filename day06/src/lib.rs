@@ -0,0 +1,63 @@
+use aoc_common::parse::parse_at;
+use aoc_common::{trace, ParseError, ParseResult, Solution};
+
+pub struct Day06;
+impl Solution for Day06 {
+    type Input = Vec<u8>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> ParseResult<Vec<u8>> {
+        input.trim().split(',')
+            .map(|s| {
+                let fish: u8 = parse_at(s, "fish timer")?;
+                if fish > 8 {
+                    return Err(ParseError::at(s, "Fish timers must be between 0 and 8"));
+                }
+                Ok(fish)
+            })
+            .collect()
+    }
+
+    fn part1(fish_list: &Vec<u8>) -> u64 {
+        count_fish_after(fish_list, 80)
+    }
+
+    fn part2(fish_list: &Vec<u8>) -> u64 {
+        count_fish_after(fish_list, 256)
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u64> {
+    Ok(Day06::part1(&Day06::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u64> {
+    Ok(Day06::part2(&Day06::parse(input)?))
+}
+
+fn count_fish_after(fish_list: &[u8], days: u32) -> u64 {
+    let mut fish_by_age = [0u64; 9];
+    for &fish in fish_list {
+        fish_by_age[fish as usize] += 1;
+    }
+
+    trace!("fish_by_age", "after day 0: {:?}", fish_by_age);
+
+    for day in 1..=days {
+        fish_by_age = [
+            fish_by_age[1],
+            fish_by_age[2],
+            fish_by_age[3],
+            fish_by_age[4],
+            fish_by_age[5],
+            fish_by_age[6],
+            fish_by_age[7] + fish_by_age[0],
+            fish_by_age[8],
+            fish_by_age[0],
+        ];
+        trace!("fish_by_age", "after day {}: {:?}", day, fish_by_age);
+    }
+
+    fish_by_age.iter().sum()
+}
@@ -0,0 +1,54 @@
+use std::ops::Range;
+use aoc_common::parse::parse_at;
+use aoc_common::{ParseResult, Solution};
+
+pub struct Day07;
+impl Solution for Day07 {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> ParseResult<Vec<i32>> {
+        input.trim().split(',')
+            .map(|s| parse_at(s, "position"))
+            .collect()
+    }
+
+    fn part1(start_positions: &Vec<i32>) -> i32 {
+        min_linear_cost(start_positions, position_range(start_positions))
+    }
+
+    fn part2(start_positions: &Vec<i32>) -> i32 {
+        min_triangular_cost(start_positions, position_range(start_positions))
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<i32> {
+    Ok(Day07::part1(&Day07::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<i32> {
+    Ok(Day07::part2(&Day07::parse(input)?))
+}
+
+fn position_range(start_positions: &[i32]) -> Range<i32> {
+    let min = *start_positions.iter().min().expect("Could not find min value");
+    let max = *start_positions.iter().max().expect("Could not find max value");
+
+    min..(max+1)
+}
+
+fn min_linear_cost(start_positions: &[i32], range: Range<i32>) -> i32 {
+    range.map(|pos| {
+        start_positions.iter().map(|p| (p - pos).abs()).sum::<i32>()
+    }).min().expect("Could not find linear minimum")
+}
+
+fn min_triangular_cost(start_positions: &[i32], range: Range<i32>) -> i32 {
+    range.map(|pos| {
+        start_positions.iter().map(|p| {
+            let delta = (p - pos).abs();
+            (delta * (delta + 1)) / 2
+        }).sum::<i32>()
+    }).min().expect("Could not find triangular minimum")
+}
@@ -0,0 +1,217 @@
+use std::collections::hash_map::RandomState;
+use std::collections::hash_set::SymmetricDifference;
+use std::collections::HashSet;
+use aoc_common::parse::split_once_at;
+use aoc_common::{ParseError, ParseResult, Solution};
+
+pub type Observation = ([String; 10], [String; 4]);
+
+pub struct Day08;
+impl Solution for Day08 {
+    type Input = Vec<Observation>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Vec<Observation>> {
+        parse_observations(input)
+    }
+
+    fn part1(observations: &Vec<Observation>) -> u32 {
+        count_unique_len_digits(observations)
+    }
+
+    fn part2(observations: &Vec<Observation>) -> u32 {
+        sum_output_values(observations)
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u32> {
+    Ok(Day08::part1(&Day08::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u32> {
+    Ok(Day08::part2(&Day08::parse(input)?))
+}
+
+pub fn parse_observations(input: &str) -> ParseResult<Vec<Observation>> {
+    input.lines().map(|line| {
+
+        let (samples, output_value) = split_once_at(line, " | ", "observation")?;
+
+        let mut samples_arr = [String::new(), String::new(), String::new(), String::new(), String::new(), String::new(), String::new(), String::new(), String::new(), String::new()];
+        parse_patterns(samples, &mut samples_arr)?;
+
+        let mut output_value_arr = [String::new(), String::new(), String::new(), String::new()];
+        parse_patterns(output_value, &mut output_value_arr)?;
+
+        Ok((samples_arr, output_value_arr))
+    }).collect()
+}
+
+fn parse_patterns(input: &str, patterns: &mut [String]) -> ParseResult<()> {
+    let words = input.split(' ').collect::<Vec<_>>();
+    if words.len() != patterns.len() {
+        return Err(ParseError::at(input, format!("Expected {} signal patterns", patterns.len())));
+    }
+    for (i, word) in words.into_iter().enumerate() {
+        if let Some((ci, c)) = word.char_indices().find(|&(_, c)| !('a'..='g').contains(&c)) {
+            return Err(ParseError::at(&word[ci..ci + c.len_utf8()], "Expected a segment between a and g"));
+        }
+        patterns[i].push_str(word);
+    }
+    Ok(())
+}
+
+fn count_unique_len_digits(observations: &[Observation]) -> u32 {
+    observations.iter().map(|(_, digits)| {
+        digits.iter().filter(|digit| {
+            matches!(digit.len(), 2 | 3 | 4 | 7)
+        }).count() as u32
+    }).sum()
+}
+
+fn sum_output_values(observations: &[Observation]) -> u32 {
+    observations.iter().map(|(signals, output_digits)| {
+        // find 2-len: "1": ab
+        let one_signal = signals.iter().find(|s| s.len() == 2).expect("Could not find '1'");
+
+        // find 3-len: "7": dab
+        let seven_signal = signals.iter().find(|s| s.len() == 3).expect("Could not find '7'");
+
+        // => top = ("7" - "1") = d
+        let top = extra_char(seven_signal, one_signal);
+
+        // find 5-len overlapping with "7": "3": fbcad
+        let three_signal = signals.iter()
+            .find(|s| s.len() == 5 && contains_all_chars(s, seven_signal))
+            .expect("Could not find '3'");
+
+        // find 4-len "4": eafb
+        let four_signal = signals.iter().find(|s| s.len() == 4).expect("Could not find '4'");
+
+        // => middle = intersection of ("3" - "1") and ("4" - "1") = intersection of (fcd) and (ef) = f
+        let middle = {
+            let three_minus_one = subtract(three_signal, one_signal);
+            let four_minus_one = subtract(four_signal, one_signal);
+            let mut intersection = three_minus_one.intersection(&four_minus_one);
+            let char = intersection.next().expect("Could not find intersecting char");
+            assert!(intersection.next().is_none(), "Found more than one intersecting char");
+            *char
+        };
+
+        // => top-left = "4" - "1" - middle = e
+        let top_left = {
+            let mut four_minus_one = subtract(four_signal, one_signal);
+            four_minus_one.remove(&middle);
+            let mut remaining = four_minus_one.iter();
+            let char = remaining.next().expect("Could not find remaining char");
+            assert!(remaining.next().is_none(), "Found more than one remaining char");
+            *char
+        };
+
+        // find 6-len overlapping with "1" and with middle: "9": cefabd
+        let nine_signal = signals.iter()
+            .find(|s| s.len() == 6 &&
+                contains_all_chars(s, one_signal) &&
+                s.contains(middle))
+            .expect("Could not find '9'");
+
+        // find 7-len: "8"
+        let eight_signal = signals.iter().find(|s| s.len() == 7).expect("Could not find '8'");
+
+        // => bottom-left = "8" - "9" = g
+        let bottom_left = {
+            let eight_minus_nine = subtract(eight_signal, nine_signal);
+            let mut remaining = eight_minus_nine.iter();
+            let char = remaining.next().expect("Could not find remaining char");
+            assert!(remaining.next().is_none(), "Found more than one remaining char");
+            *char
+        };
+
+        // find other 6-len with middle: "6": cdfgeb
+        let six_signal = signals.iter()
+            .find(|s| s.len() == 6 &&
+                *s != nine_signal &&
+                s.contains(middle))
+            .expect("Could not find '6'");
+
+        // => top-right = "8" - "6" = a
+        let top_right = {
+            let eight_minus_six = subtract(eight_signal, six_signal);
+            let mut remaining = eight_minus_six.iter();
+            let char = remaining.next().expect("Could not find remaining char");
+            assert!(remaining.next().is_none(), "Found more than one remaining char");
+            *char
+        };
+
+        // => bottom-right = "1" - top-right = b
+        let bottom_right = {
+            let one_minus_top_right = subtract(one_signal, &top_right.to_string());
+            let mut remaining = one_minus_top_right.iter();
+            let char = remaining.next().expect("Could not find remaining char");
+            assert!(remaining.next().is_none(), "Found more than one remaining char");
+            *char
+        };
+
+        // => bottom = "8" - top - middle - top-left - bottom-left - top-right - bottom-right = c
+        let bottom = {
+            let mut eight_chars: HashSet<char, RandomState> = HashSet::from_iter(eight_signal.chars());
+            eight_chars.remove(&top);
+            eight_chars.remove(&middle);
+            eight_chars.remove(&top_left);
+            eight_chars.remove(&bottom_left);
+            eight_chars.remove(&top_right);
+            eight_chars.remove(&bottom_right);
+            let mut remaining = eight_chars.iter();
+            let char = remaining.next().expect("Could not find remaining char");
+            assert!(remaining.next().is_none(), "Found more than one remaining char");
+            *char
+        };
+
+        let mut numbers = String::new();
+        for digit in output_digits {
+            let pattern = [&top, &top_left, &top_right, &middle, &bottom_left, &bottom_right, &bottom]
+                .map(|c| digit.contains(|dc| &dc == c));
+
+            let number = match pattern {
+                [true, true, true, false, true, true, true] => '0',
+                [false, false, true, false, false, true, false] => '1',
+                [true, false, true, true, true, false, true] => '2',
+                [true, false, true, true, false, true, true] => '3',
+                [false, true, true, true, false, true, false] => '4',
+                [true, true, false, true, false, true, true] => '5',
+                [true, true, false, true, true, true, true] => '6',
+                [true, false, true, false, false, true, false] => '7',
+                [true, true, true, true, true, true, true] => '8',
+                [true, true, true, true, false, true, true] => '9',
+                _ => panic!("Unexpected 7-segment pattern")
+            };
+            numbers.push(number);
+        }
+
+        let numbers_int = numbers.parse::<u32>().expect("Could not parse numbers");
+        // println!("{} => {}", numbers, numbers_int);
+        numbers_int
+    }).sum()
+}
+
+fn extra_char(a: &str, b: &str) -> char {
+    let a_chars_set = HashSet::from_iter(a.chars());
+    let b_chars_set = HashSet::from_iter(b.chars());
+    let mut diff_chars: SymmetricDifference<char, RandomState> = a_chars_set.symmetric_difference(&b_chars_set);
+    let char = diff_chars.next().expect("Could not find a symmetric difference character");
+    assert!(diff_chars.next().is_none(), "Found more than one symmetric difference character");
+    *char
+}
+
+fn contains_all_chars(longer: &str, shorter: &str) -> bool {
+    let longer_chars_set: HashSet<char, RandomState> = HashSet::from_iter(longer.chars());
+    let shorter_chars_set: HashSet<char, RandomState> = HashSet::from_iter(shorter.chars());
+    longer_chars_set.is_superset(&shorter_chars_set)
+}
+
+fn subtract(longer: &str, shorter: &str) -> HashSet<char> {
+    let longer_chars_set: HashSet<char, RandomState> = HashSet::from_iter(longer.chars());
+    let shorter_chars_set: HashSet<char, RandomState> = HashSet::from_iter(shorter.chars());
+    longer_chars_set.difference(&shorter_chars_set).copied().collect()
+}
\ No newline at end of file
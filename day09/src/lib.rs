@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+use aoc_common::coord::Pos;
+use aoc_common::{trace, Grid, ParseResult, Solution};
+
+pub struct Day09;
+impl Solution for Day09 {
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Grid<u32>> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(map: &Grid<u32>) -> u32 {
+        total_risk(map)
+    }
+
+    fn part2(map: &Grid<u32>) -> u32 {
+        top_three_basins_multiplied(map)
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u32> {
+    Ok(Day09::part1(&Day09::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u32> {
+    Ok(Day09::part2(&Day09::parse(input)?))
+}
+
+fn low_points(map: &Grid<u32>) -> Vec<Pos> {
+    map.iter()
+        .filter(|(pos, point_height)| map.neighbours4(pos).all(|n| *point_height < &map[n]))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn total_risk(map: &Grid<u32>) -> u32 {
+    low_points(map).iter()
+        .map(|&low| map[low] + 1)
+        .sum()
+}
+
+fn top_three_basins_multiplied(map: &Grid<u32>) -> u32 {
+    let mut basin_sizes = low_points(map).iter().map(|low| {
+        let mut basin_points = HashSet::new();
+        let mut visited =  HashSet::new();
+        visited.insert(*low);
+        let mut queue = vec![*low];
+
+        trace!("basin", "low point ({}, {})", low.x, low.y);
+        while let Some(next) = queue.pop() {
+            basin_points.insert(next);
+            trace!("basin_point", "visited ({}, {})", next.x, next.y);
+
+            for neighbour in map.neighbours4(&next) {
+                if !visited.contains(&neighbour) && map[neighbour] != 9 {
+                    queue.push(neighbour);
+                    visited.insert(neighbour);
+                }
+            }
+        }
+        basin_points.len() as u32
+    }).collect::<Vec<u32>>();
+
+    basin_sizes.sort();
+    basin_sizes.reverse();
+    basin_sizes.iter()
+        .take(3)
+        .copied()
+        .reduce(|acum, item| acum * item)
+        .expect("Could not multiply top three")
+}
@@ -0,0 +1,106 @@
+use aoc_common::{ParseError, ParseResult, Solution};
+
+pub struct Day10;
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> ParseResult<Vec<String>> {
+        input.lines().map(|line| {
+            if let Some((i, c)) = line.char_indices().find(|&(_, c)| !"()[]{}<>".contains(c)) {
+                return Err(ParseError::at(&line[i..i + c.len_utf8()], "Expected a bracket"));
+            }
+            Ok(line.to_string())
+        }).collect()
+    }
+
+    fn part1(lines: &Vec<String>) -> u32 {
+        lines.iter()
+            .filter_map(|line| match parse_chunk(line) {
+                ChunkParseResult::Corrupted(bracket) => Some(corrupted_score(&bracket)),
+                ChunkParseResult::Incomplete(_) => None,
+            })
+            .sum()
+    }
+
+    fn part2(lines: &Vec<String>) -> u128 {
+        let mut incomplete_scores: Vec<u128> = lines.iter()
+            .filter_map(|line| match parse_chunk(line) {
+                ChunkParseResult::Corrupted(_) => None,
+                ChunkParseResult::Incomplete(mut open_brackets) => Some(incomplete_score(&mut open_brackets)),
+            })
+            .collect();
+        incomplete_scores.sort();
+        *incomplete_scores.get(incomplete_scores.len()/2)
+            .expect("Could not find median incomplete score")
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u32> {
+    Ok(Day10::part1(&Day10::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u128> {
+    Ok(Day10::part2(&Day10::parse(input)?))
+}
+
+enum ChunkParseResult {
+    Incomplete(Vec<char>),
+    Corrupted(char),
+}
+
+fn parse_chunk(chunk: &str) -> ChunkParseResult {
+    let mut open_brackets = vec![];
+    for bracket in chunk.chars() {
+        match bracket {
+            '(' | '[' | '{' | '<' => {
+                open_brackets.push(bracket);
+            },
+            ')' | ']' | '}' | '>' => {
+                if open_brackets.is_empty() {
+                    return ChunkParseResult::Incomplete(open_brackets);
+                } else {
+                    let most_recent_open = open_brackets.pop().expect("Could not get most recent bracket");
+                    let expected = match bracket {
+                        ')' => '(',
+                        ']' => '[',
+                        '}' => '{',
+                        '>' => '<',
+                        _ => panic!("Unexpected close bracket type")
+                    };
+                    if most_recent_open != expected {
+                        return ChunkParseResult::Corrupted(bracket);
+                    }
+                }
+            }
+            _ => unreachable!("Unexpected bracket char {}", bracket)
+        }
+    }
+    ChunkParseResult::Incomplete(open_brackets) // open_brackets could be []
+}
+
+fn corrupted_score(bracket: &char) -> u32 {
+    match bracket {
+        ')' => 3u32,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => panic!("Unexpected close bracket type")
+    }
+}
+
+fn incomplete_score(open_brackets: &mut [char]) -> u128 {
+    open_brackets.reverse();
+    open_brackets.iter().map(|open| {
+        match open {
+            '(' => 1u128,
+            '[' => 2,
+            '{' => 3,
+            '<' => 4,
+            _ => panic!("Unexpected open bracket type")
+        }
+    }).reduce(|acc, score| {
+        (acc * 5) + score
+    }).expect("Could not calc total score")
+}
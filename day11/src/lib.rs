@@ -0,0 +1,139 @@
+use std::fmt::{Display, Formatter};
+use aoc_common::render::{Animation, Frame};
+use aoc_common::{trace, Answer, Grid, ParseResult, Solution};
+
+type Octo = (u32, bool);
+#[derive(Clone)]
+pub struct OctoMap {
+    octos: Grid<Octo>
+}
+
+impl OctoMap {
+    pub fn parse(string: &str) -> ParseResult<OctoMap> {
+        let octos = Grid::parse_digits(string)?.map(|&energy| (energy, false));
+        Ok(OctoMap { octos })
+    }
+
+    pub fn step(&mut self) -> u32 {
+        // Reset octos to not having flashed
+        for (_, octo) in self.octos.iter_mut() {
+            octo.1 = false
+        }
+
+        let mut flash_count = 0;
+
+        // Increment all octos
+        let mut to_flash = vec![];
+        for (pos, octo) in self.octos.iter_mut() {
+            octo.0 += 1;
+            if octo.0 > 9 {
+                octo.1 = true;
+                octo.0 = 0;
+                to_flash.push(pos);
+                flash_count += 1;
+            }
+        }
+
+        // Process flashes
+        while let Some(pos) = to_flash.pop() {
+            let neighbours = self.octos.neighbours8(&pos).collect::<Vec<_>>();
+            for neighbour in neighbours {
+                let octo = &mut self.octos[neighbour];
+                if !octo.1 {
+                    octo.0 += 1;
+                    if octo.0 > 9 {
+                        octo.1 = true;
+                        octo.0 = 0;
+                        trace!("flash", "({}, {}) set off by ({}, {})", neighbour.x, neighbour.y, pos.x, pos.y);
+                        to_flash.push(neighbour);
+                        flash_count += 1;
+                    }
+                }
+            }
+        }
+        
+        flash_count
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.octos, 11, |&(energy, flashed)| if flashed { 10 } else { energy as u8 })
+    }
+}
+
+pub struct SyncStep {
+    pub step: u32,
+    pub flashes: u32,
+}
+impl Display for SyncStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({} flashes)", self.step, self.flashes)
+    }
+}
+impl Answer for SyncStep {
+    fn value(&self) -> String {
+        self.step.to_string()
+    }
+
+    fn metadata(&self) -> Vec<(&'static str, String)> {
+        vec![("flashes", self.flashes.to_string())]
+    }
+}
+
+pub struct Day11;
+impl Solution for Day11 {
+    type Input = OctoMap;
+    type Answer1 = u32;
+    type Answer2 = SyncStep;
+
+    fn parse(input: &str) -> ParseResult<OctoMap> {
+        OctoMap::parse(input)
+    }
+
+    fn part1(map: &OctoMap) -> u32 {
+        let mut map = map.clone();
+        let mut total_flashes = 0;
+        for step in 1..101 {
+            let flashes = map.step();
+            trace!("step", "step {}: {} flashes", step, flashes);
+            total_flashes += flashes;
+        }
+        total_flashes
+    }
+
+    fn part2(map: &OctoMap) -> SyncStep {
+        let mut map = map.clone();
+        for step in 1..10000 {
+            let flashes = map.step();
+            trace!("step", "step {}: {} flashes", step, flashes);
+            if flashes as usize == map.octos.len() {
+                return SyncStep { step, flashes };
+            }
+        }
+        panic!("Octopuses never all flashed together");
+    }
+}
+
+// Octopuses are drawn by energy level, with those that flashed this step brightest
+impl Animation for Day11 {
+    const PALETTE: &'static str = "heat";
+
+    fn frames(map: &OctoMap) -> Vec<Frame> {
+        let mut map = map.clone();
+        let mut frames = vec![map.frame()];
+        loop {
+            let flashes = map.step();
+            frames.push(map.frame());
+            if flashes as usize == map.octos.len() || frames.len() > 10000 {
+                return frames;
+            }
+        }
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u32> {
+    Ok(Day11::part1(&Day11::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<SyncStep> {
+    Ok(Day11::part2(&Day11::parse(input)?))
+}
@@ -0,0 +1,110 @@
+use std::collections::{HashMap, HashSet};
+use aoc_common::parse::split_once_at;
+use aoc_common::{ParseError, ParseResult, Solution};
+
+pub struct Network {
+    neighbours: HashMap<String, Vec<String>>,
+}
+
+impl Network {
+    pub fn parse(input: &str) -> ParseResult<Network> {
+        let mut neighbours: HashMap<String, Vec<String>> = HashMap::new();
+
+        for line in input.lines() {
+            let (left, right) = split_once_at(line, "-", "connection")?;
+
+            neighbours.entry(left.to_string()).or_default().push(right.to_string());
+            neighbours.entry(right.to_string()).or_default().push(left.to_string());
+        }
+
+        if !neighbours.contains_key("start") {
+            return Err(ParseError::new("No connections to the start cave"));
+        }
+        Ok(Network { neighbours })
+    }
+
+    pub fn count_paths(&self, can_revisit_one: bool) -> u32 {
+        Pathfinder::new(self, can_revisit_one).count_paths("start")
+    }
+}
+
+struct Pathfinder<'a> {
+    network: &'a Network,
+    visited: HashSet<&'a str>,
+    can_revisit_one: bool,
+    has_revisited: bool
+}
+
+trait CaveString {
+    fn is_small(&self) -> bool;
+}
+impl CaveString for str {
+    fn is_small(&self) -> bool {
+        self.chars().all(|c| c.is_lowercase())
+    }
+}
+
+impl <'a> Pathfinder<'a> {
+    fn new(network: &'a Network, can_revisit_one: bool) -> Pathfinder<'a> {
+        Pathfinder {
+            network,
+            visited: HashSet::new(),
+            can_revisit_one,
+            has_revisited: false
+        }
+    }
+
+    fn count_paths(&mut self, from: &'a str) -> u32 {
+        let mut paths = 0;
+
+        let should_remove_from_visited = from.is_small() && self.visited.insert(from);
+
+        for neighbour in self.network.neighbours.get(from).unwrap() {
+            let neighbour = neighbour.as_str();
+            if neighbour == "end" {
+                paths += 1;
+            } else {
+                if !neighbour.is_small() || !self.visited.contains(neighbour) {
+                    paths += self.count_paths(neighbour);
+                } else if neighbour != "start" && self.can_revisit_one && !self.has_revisited {
+                    self.has_revisited = true;
+                    paths += self.count_paths(neighbour);
+                    self.has_revisited = false;
+                }
+            }
+        }
+
+        if should_remove_from_visited {
+            self.visited.remove(from);
+        }
+
+        paths
+    }
+}
+
+pub struct Day12;
+impl Solution for Day12 {
+    type Input = Network;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Network> {
+        Network::parse(input)
+    }
+
+    fn part1(network: &Network) -> u32 {
+        network.count_paths(false)
+    }
+
+    fn part2(network: &Network) -> u32 {
+        network.count_paths(true)
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u32> {
+    Ok(Day12::part1(&Day12::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u32> {
+    Ok(Day12::part2(&Day12::parse(input)?))
+}
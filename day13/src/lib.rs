@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+use aoc_common::parse::{parse_at, split_once_at};
+use aoc_common::render::{Animation, Frame};
+use aoc_common::{ParseError, ParseResult, Solution};
+
+#[derive(Copy, Clone, Debug)]
+pub enum Fold {
+    X(u32),
+    Y(u32)
+}
+
+pub struct Manual {
+    pub dots: Vec<(u32, u32)>,
+    pub folds: Vec<Fold>,
+}
+impl Manual {
+    fn dots_after_folds(&self, num_folds: usize) -> HashSet<(u32, u32)> {
+        let mut dots = self.dots.clone();
+        for fold in self.folds.iter().take(num_folds) {
+            for (x, y) in dots.iter_mut() {
+                match *fold {
+                    Fold::X(fx) => if *x > fx { *x = fx - (*x - fx); }
+                    Fold::Y(fy) => if *y > fy { *y = fy - (*y - fy); }
+                }
+            }
+        }
+        dots.into_iter().collect()
+    }
+}
+
+pub struct Day13;
+impl Solution for Day13 {
+    type Input = Manual;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> ParseResult<Manual> {
+        let (dots_input, folds_input) = input.split_once("\n\n")
+            .ok_or_else(|| ParseError::new("Expected a blank line between the dots and the folds"))?;
+
+        let dots = dots_input.lines().map(|line| {
+            let (xstr, ystr) = split_once_at(line, ",", "dot")?;
+            Ok((parse_at(xstr, "x")?, parse_at(ystr, "y")?))
+        }).collect::<ParseResult<Vec<_>>>()?;
+
+        let folds = folds_input.lines().map(|line| {
+            let instruction = line.strip_prefix("fold along ")
+                .ok_or_else(|| ParseError::at(line, "Expected a fold like 'fold along x=5'"))?;
+            let (axis, numstr) = split_once_at(instruction, "=", "fold")?;
+            let num = parse_at(numstr, "fold position")?;
+            match axis {
+                "x" => Ok(Fold::X(num)),
+                "y" => Ok(Fold::Y(num)),
+                _ => Err(ParseError::at(axis, "Unknown axis for fold")),
+            }
+        }).collect::<ParseResult<Vec<_>>>()?;
+
+        Ok(Manual { dots, folds })
+    }
+
+    fn part1(manual: &Manual) -> usize {
+        manual.dots_after_folds(1).len()
+    }
+
+    fn part2(manual: &Manual) -> String {
+        render_dots(&manual.dots_after_folds(manual.folds.len()))
+    }
+}
+
+// The sheet of paper is drawn before any folds and after each one, shrinking as it's folded
+impl Animation for Day13 {
+    const PALETTE: &'static str = "paper";
+
+    fn frames(manual: &Manual) -> Vec<Frame> {
+        let mut width = manual.dots.iter().map(|&(x, _)| x + 1).max().unwrap_or(1);
+        let mut height = manual.dots.iter().map(|&(_, y)| y + 1).max().unwrap_or(1);
+        let mut frames = vec![dots_frame(&manual.dots_after_folds(0), width, height)];
+        for (i, fold) in manual.folds.iter().enumerate() {
+            match *fold {
+                Fold::X(fx) => width = fx,
+                Fold::Y(fy) => height = fy,
+            }
+            frames.push(dots_frame(&manual.dots_after_folds(i + 1), width, height));
+        }
+        frames
+    }
+}
+
+fn dots_frame(dots: &HashSet<(u32, u32)>, width: u32, height: u32) -> Frame {
+    let mut frame = Frame::new(width as usize, height as usize, 2);
+    for &(x, y) in dots {
+        if x < width && y < height {
+            frame.set(x as usize, y as usize, 1);
+        }
+    }
+    frame
+}
+
+pub fn part1(input: &str) -> ParseResult<usize> {
+    Ok(Day13::part1(&Day13::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<String> {
+    Ok(Day13::part2(&Day13::parse(input)?))
+}
+
+fn render_dots(set: &HashSet<(u32, u32)>) -> String {
+    let max_x = set.iter().map(|(x, _)| x).max().expect("Could not find max x");
+    let max_y = set.iter().map(|(_, y)| y).max().expect("Could not find max y");
+    let mut result = String::new();
+    for y in 0..(max_y+1) {
+        for x in 0..(max_x+1) {
+            if set.contains(&(x, y)) {
+                result.push('#');
+            } else {
+                result.push(' ');
+            }
+        }
+        if y < *max_y {
+            result.push('\n');
+        }
+    }
+    result
+}
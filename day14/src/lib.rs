@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use aoc_common::parse::split_once_at;
+use aoc_common::{ParseError, ParseResult, Solution};
+
+pub struct Polymer {
+    pub template: String,
+    pub rules: HashMap<[char; 2], char>,
+}
+impl Polymer {
+    fn max_minus_min_after(&self, steps: usize) -> u64 {
+        let mut pair_counts: HashMap<[char; 2], u64> = self.template.chars()
+            .collect::<Vec<_>>()
+            .array_windows()
+            .fold(HashMap::new(), |mut acc, pair| {
+                *acc.entry(*pair).or_default() += 1u64;
+                acc
+            });
+
+        for _ in 0..steps {
+            let mut new_pair_counts: HashMap<[char; 2], u64> = HashMap::new();
+            for (pair, count) in pair_counts {
+                if let Some(&insert) = self.rules.get(&pair) {
+                    *new_pair_counts.entry([pair[0], insert]).or_default() += count;
+                    *new_pair_counts.entry([insert, pair[1]]).or_default() += count;
+                } else {
+                    *new_pair_counts.entry(pair).or_default() += count;
+                }
+            }
+
+            pair_counts = new_pair_counts;
+        }
+
+        max_minus_min_chars(&pair_counts)
+    }
+}
+
+pub struct Day14;
+impl Solution for Day14 {
+    type Input = Polymer;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> ParseResult<Polymer> {
+        let (template, rules) = input.split_once("\n\n")
+            .ok_or_else(|| ParseError::new("Expected a blank line between the template and the rules"))?;
+        if template.chars().count() < 2 {
+            return Err(ParseError::at(template, "The template must have at least two elements"));
+        }
+
+        let rules = rules.lines().map(|line| {
+            let (pair, insert) = split_once_at(line, " -> ", "rule")?;
+
+            let pair_chars = pair.chars().collect::<Vec<_>>();
+            let insert_chars = insert.chars().collect::<Vec<_>>();
+            match (pair_chars.as_slice(), insert_chars.as_slice()) {
+                (&[first, second], &[insert]) => Ok(([first, second], insert)),
+                ([_, _], _) => Err(ParseError::at(insert, "Expected a single element to insert")),
+                _ => Err(ParseError::at(pair, "Expected a pair of elements")),
+            }
+        }).collect::<ParseResult<HashMap<_, _>>>()?;
+
+        Ok(Polymer { template: template.to_string(), rules })
+    }
+
+    fn part1(polymer: &Polymer) -> u64 {
+        polymer.max_minus_min_after(10)
+    }
+
+    fn part2(polymer: &Polymer) -> u64 {
+        polymer.max_minus_min_after(40)
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u64> {
+    Ok(Day14::part1(&Day14::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u64> {
+    Ok(Day14::part2(&Day14::parse(input)?))
+}
+
+fn max_minus_min_chars(pair_counts: &HashMap<[char; 2], u64>) -> u64 {
+    let char_counts: HashMap<char, u64> = pair_counts.iter()
+        .fold(HashMap::new(), |mut acc, (pair, count)| {
+            *acc.entry(pair[0]).or_default() += count;
+            *acc.entry(pair[1]).or_default() += count;
+            acc
+        });
+
+    let max = char_counts.iter().max_by_key(|(_, v)| *v).unwrap();
+    let min = char_counts.iter().min_by_key(|(_, v)| *v).unwrap();
+    let max_count = max.1.div_ceil(2);
+    let min_count = min.1.div_ceil(2);
+    // println!("{:?}, {:?}", (max.0, max_count), (min.0, min_count));
+    max_count - min_count
+}
@@ -0,0 +1,97 @@
+use aoc_common::coord::Pos;
+use aoc_common::search::{a_star, Path, SearchProblem};
+use aoc_common::{Grid, ParseError, ParseResult, Solution};
+
+pub struct Day15;
+impl Solution for Day15 {
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Grid<u32>> {
+        let risks_map = Grid::parse_digits(input)?;
+        // A risk of 0 would let the search's heuristic overestimate, and can't be expanded
+        if let Some((line, i)) = input.lines().find_map(|line| line.find('0').map(|i| (line, i))) {
+            return Err(ParseError::at(&line[i..i + 1], "Expected a risk level from 1 to 9"));
+        }
+        Ok(risks_map)
+    }
+
+    fn part1(risks_map: &Grid<u32>) -> u32 {
+        lowest_total_risk(risks_map)
+    }
+
+    fn part2(risks_map: &Grid<u32>) -> u32 {
+        lowest_total_risk(&expand(risks_map))
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u32> {
+    Ok(Day15::part1(&Day15::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u32> {
+    Ok(Day15::part2(&Day15::parse(input)?))
+}
+
+fn lowest_total_risk(risks_map: &Grid<u32>) -> u32 {
+    let max_x = risks_map.width() - 1;
+    let max_y = risks_map.height() - 1;
+    shortest_path(risks_map, Pos::new(0, 0), Pos::new(max_x, max_y))
+        .expect("Could not find a path to the goal")
+        .cost
+}
+
+pub fn expand(risks_map: &Grid<u32>) -> Grid<u32> {
+    let rows = [0,1,2,3,4].into_iter().flat_map(|y_repeat| {
+        risks_map.rows().map(move |row| {
+            [0,1,2,3,4].into_iter().flat_map(|x_repeat| {
+                row.iter().map(move |&risk| ((risk + y_repeat + x_repeat - 1) % 9) + 1)
+            }).collect::<Vec<_>>()
+        })
+    }).collect::<Vec<_>>();
+    Grid::from_rows(rows).expect("Could not expand risks map")
+}
+
+// Moving onto a position costs its risk level
+struct RiskMap<'a> {
+    entry_cost_map: &'a Grid<u32>,
+    goal: Pos,
+}
+impl SearchProblem for RiskMap<'_> {
+    type State = Pos;
+    type Cost = u32;
+
+    fn neighbours(&self, position: &Pos) -> Vec<(Pos, u32)> {
+        self.entry_cost_map.neighbours4(position)
+            .map(|neighbour| (neighbour, self.entry_cost_map[neighbour]))
+            .collect()
+    }
+
+    fn is_goal(&self, position: &Pos) -> bool {
+        *position == self.goal
+    }
+
+    // Every step costs at least 1 (see `Day15::parse`), so the Manhattan distance never overestimates
+    fn heuristic(&self, position: &Pos) -> u32 {
+        (position.x.abs_diff(self.goal.x) + position.y.abs_diff(self.goal.y)) as u32
+    }
+}
+
+pub fn shortest_path(entry_cost_map: &Grid<u32>, start: Pos, goal: Pos) -> Option<Path<Pos, u32>> {
+    a_star(&RiskMap { entry_cost_map, goal }, start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_risk_is_rejected() {
+        let input = "19\n10";
+        let error = Day15::parse(input).err().unwrap().locate(input);
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (2, 2));
+        assert_eq!(part1("19\n11"), Ok(2));
+    }
+}
@@ -0,0 +1,348 @@
+use bitvec::prelude::*;
+use num::Integer;
+use aoc_common::{ParseError, ParseResult, Solution};
+
+struct BitSliceReader<'a> {
+    hex: &'a str,
+    bit_slice: &'a BitSlice<u8, Msb0>,
+    cur_bit: usize,
+}
+impl <'a> BitSliceReader<'a> {
+    fn new(hex: &'a str, input: &'a [u8]) -> BitSliceReader<'a> {
+        let bit_slice = BitSlice::<u8, Msb0>::try_from_slice(input)
+            .expect("Could not parse to BitSlice");
+        BitSliceReader {
+            hex,
+            bit_slice,
+            cur_bit: 0,
+        }
+    }
+
+    // An error pointing at the hex digit containing the given bit
+    fn error_at(&self, bit: usize, message: &str) -> ParseError {
+        let digit = std::cmp::min(bit / 4, self.hex.len().saturating_sub(1));
+        ParseError::at(&self.hex[digit..(digit + 1).min(self.hex.len())], format!("{} (bit {})", message, bit))
+    }
+
+    fn ensure_available(&self, num_bits: usize) -> ParseResult<()> {
+        if self.cur_bit + num_bits > self.bit_slice.len() {
+            Err(self.error_at(self.cur_bit, "Packet ended unexpectedly"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn read_bits<N: Integer + PartialOrd + Copy>(&mut self, num_bits: usize) -> ParseResult<N> {
+        self.ensure_available(num_bits)?;
+        let result: N = (self.cur_bit..(self.cur_bit+num_bits))
+            .fold(N::zero(), |acc, bit_index| {
+                let b = self.bit_slice[bit_index];
+                let mut result = acc * (N::one() + N::one());
+                if b {
+                    result = result + N::one();
+                }
+                result
+            });
+        self.cur_bit += num_bits;
+        Ok(result)
+    }
+
+    fn read_bool(&mut self) -> ParseResult<bool> {
+        self.ensure_available(1)?;
+        let result = self.bit_slice[self.cur_bit];
+        self.cur_bit += 1;
+        Ok(result)
+    }
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Header {
+    packet_version: u8,
+    packet_type: u8,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum Packet {
+    Literal(Header, u128),
+    Operator(Header, Vec<Packet>),
+}
+impl Packet {
+    pub fn parse(input: &str) -> ParseResult<Packet> {
+        if let Some((i, c)) = input.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(ParseError::at(&input[i..i + c.len_utf8()], "Expected a hex digit"));
+        }
+        let parsed_hex = hex::decode(input)
+            .map_err(|_| ParseError::at(input, "Expected an even number of hex digits"))?;
+        let mut parser = PacketParser::new(input, &parsed_hex);
+        parser.packet()
+    }
+
+    pub fn sum_version_numbers(&self) -> u128 {
+        match self {
+            Packet::Literal(header, _) => header.packet_version as u128,
+            Packet::Operator(header, subpackets) => {
+                header.packet_version as u128 + subpackets.iter().map(|p| p.sum_version_numbers()).sum::<u128>()
+            }
+        }
+    }
+
+    pub fn value(&self) -> u128 {
+        match self {
+            Packet::Literal(_, v) => *v,
+            Packet::Operator(h, subpackets) => {
+                ops::from(&h.packet_type)(subpackets)
+            }
+        }
+    }
+}
+
+mod ops {
+    use super::*;
+
+    pub(crate) fn from(packet_type: &u8) -> fn(&[Packet]) -> u128 {
+        match packet_type {
+            0 => sum,
+            1 => product,
+            2 => minimum,
+            3 => maximum,
+            5 => greater_than,
+            6 => less_than,
+            7 => equal_to,
+            _ => unreachable!("Unknown operator type {}", packet_type),
+        }
+    }
+
+    fn sum(packets: &[Packet]) -> u128 {
+        packets.iter().map(|p| p.value()).sum()
+    }
+
+    fn product(packets: &[Packet]) -> u128 {
+        packets.iter().map(|p| p.value()).product()
+    }
+
+    fn minimum(packets: &[Packet]) -> u128 {
+        packets.iter().map(|p| p.value()).min().unwrap()
+    }
+
+    fn maximum(packets: &[Packet]) -> u128 {
+        packets.iter().map(|p| p.value()).max().unwrap()
+    }
+
+    fn greater_than(packets: &[Packet]) -> u128 {
+        let (first, second) = get_two_packets(packets);
+        if first.value() > second.value() { 1 } else { 0 }
+    }
+
+    fn less_than(packets: &[Packet]) -> u128 {
+        let (first, second) = get_two_packets(packets);
+        if first.value() < second.value() { 1 } else { 0 }
+    }
+
+    fn equal_to(packets: &[Packet]) -> u128 {
+        let (first, second) = get_two_packets(packets);
+        if first.value() == second.value() { 1 } else { 0 }
+    }
+
+    fn get_two_packets(packets: &[Packet]) -> (&Packet, &Packet) {
+        assert_eq!(packets.len(), 2);
+        let first = &packets[0];
+        let second = &packets[1];
+        (first, second)
+    }
+}
+
+struct PacketParser<'a> {
+    bit_slice_reader: BitSliceReader<'a>
+}
+impl <'a> PacketParser<'a> {
+    fn new(hex: &'a str, input: &'a [u8]) -> PacketParser<'a> {
+        PacketParser {
+            bit_slice_reader: BitSliceReader::new(hex, input)
+        }
+    }
+
+    fn packet(&mut self) -> ParseResult<Packet> {
+        let start_bit = self.bit_slice_reader.cur_bit;
+        let header = self.header()?;
+
+        if header.packet_type == 4 {
+            self.literal_packet(header)
+        } else {
+            let packet = self.operator_packet(header)?;
+            if let Packet::Operator(header, subpackets) = &packet {
+                let valid = match header.packet_type {
+                    0..=3 => !subpackets.is_empty(),
+                    5..=7 => subpackets.len() == 2,
+                    _ => false,
+                };
+                if !valid {
+                    let message = format!("Invalid operator packet (type {} with {} subpackets)", header.packet_type, subpackets.len());
+                    return Err(self.bit_slice_reader.error_at(start_bit, &message));
+                }
+            }
+            Ok(packet)
+        }
+    }
+
+    fn header(&mut self) -> ParseResult<Header> {
+        let version_byte = self.bit_slice_reader.read_bits(3)?;
+        let type_byte = self.bit_slice_reader.read_bits(3)?;
+        Ok(Header { packet_version: version_byte, packet_type: type_byte })
+    }
+
+    fn literal_packet(&mut self, header: Header) -> ParseResult<Packet> {
+        let start_bit = self.bit_slice_reader.cur_bit;
+        let mut should_continue = true;
+        let mut result: u128 = 0;
+
+        while should_continue {
+            should_continue = self.bit_slice_reader.read_bool()?;
+            let byte: u128 = self.bit_slice_reader.read_bits(4)?;
+            result = result.checked_mul(16)
+                .map(|r| r + byte)
+                .ok_or_else(|| self.bit_slice_reader.error_at(start_bit, "Literal value is too large"))?;
+        }
+
+        Ok(Packet::Literal(header, result))
+    }
+
+    fn operator_packet(&mut self, header: Header) -> ParseResult<Packet> {
+        let is_length_type_1 = self.bit_slice_reader.read_bool()?;
+        let subpackets = if is_length_type_1 {
+            self.operator_packet_length_type_1()?
+        } else {
+            self.operator_packet_length_type_0()?
+        };
+        Ok(Packet::Operator(header, subpackets))
+    }
+
+    fn operator_packet_length_type_0(&mut self) -> ParseResult<Vec<Packet>> {
+        let subpackets_bit_length: u16 = self.bit_slice_reader.read_bits(15)?;
+        let terminate_at = self.bit_slice_reader.cur_bit + subpackets_bit_length as usize;
+
+        let mut subpackets = vec![];
+        while self.bit_slice_reader.cur_bit < terminate_at {
+            subpackets.push(self.packet()?)
+        }
+
+        Ok(subpackets)
+    }
+
+    fn operator_packet_length_type_1(&mut self) -> ParseResult<Vec<Packet>> {
+        let num_subpackets: u16 = self.bit_slice_reader.read_bits(11)?;
+
+        let mut subpackets = vec![];
+        for _ in 0..num_subpackets {
+            subpackets.push(self.packet()?)
+        }
+
+        Ok(subpackets)
+    }
+}
+
+pub struct Day16;
+impl Solution for Day16 {
+    type Input = Packet;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> ParseResult<Packet> {
+        Packet::parse(input.trim())
+    }
+
+    fn part1(packet: &Packet) -> u128 {
+        packet.sum_version_numbers()
+    }
+
+    fn part2(packet: &Packet) -> u128 {
+        packet.value()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u128> {
+    Ok(Day16::part1(&Day16::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u128> {
+    Ok(Day16::part2(&Day16::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literal_packet_example() {
+        let input = "D2FE28";
+        let packet = Packet::parse(input).unwrap();
+        assert_eq!(packet, Packet::Literal(Header { packet_version: 6, packet_type: 4}, 2021));
+    }
+
+    #[test]
+    fn test_operator_packet_example_type_0() {
+        let input = "38006F45291200";
+        let packet = Packet::parse(input).unwrap();
+        assert_eq!(packet, Packet::Operator(
+            Header { packet_version: 1, packet_type: 6 },
+            vec![
+                Packet::Literal(
+                    Header { packet_version: 6, packet_type: 4 },
+                    10
+                ),
+                Packet::Literal(
+                    Header { packet_version: 2, packet_type: 4 },
+                    20
+                )
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_operator_packet_example_type_1() {
+        let input = "EE00D40C823060";
+        let packet = Packet::parse(input).unwrap();
+        assert_eq!(packet, Packet::Operator(
+            Header { packet_version: 7, packet_type: 3 },
+            vec![
+                Packet::Literal(
+                    Header { packet_version: 2, packet_type: 4 },
+                    1
+                ),
+                Packet::Literal(
+                    Header { packet_version: 4, packet_type: 4 },
+                    2
+                ) ,
+                Packet::Literal(
+                    Header { packet_version: 1, packet_type: 4 },
+                    3
+                )
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_sum_version_numbers() {
+        assert_eq!(Packet::parse("8A004A801A8002F478").unwrap().sum_version_numbers(), 16);
+        assert_eq!(Packet::parse("620080001611562C8802118E34").unwrap().sum_version_numbers(), 12);
+        assert_eq!(Packet::parse("C0015000016115A2E0802F182340").unwrap().sum_version_numbers(), 23);
+        assert_eq!(Packet::parse("A0016C880162017C3686B18A3D4780").unwrap().sum_version_numbers(), 31);
+    }
+
+    #[test]
+    fn test_value() {
+        assert_eq!(Packet::parse("C200B40A82").unwrap().value(), 3);
+        assert_eq!(Packet::parse("04005AC33890").unwrap().value(), 54);
+        assert_eq!(Packet::parse("880086C3E88112").unwrap().value(), 7);
+        assert_eq!(Packet::parse("CE00C43D881120").unwrap().value(), 9);
+        assert_eq!(Packet::parse("D8005AC2A8F0").unwrap().value(), 1);
+        assert_eq!(Packet::parse("F600BC2D8F").unwrap().value(), 0);
+        assert_eq!(Packet::parse("9C005AC2F8F0").unwrap().value(), 0);
+        assert_eq!(Packet::parse("9C0141080250320F1802104A08").unwrap().value(), 1);
+    }
+
+    #[test]
+    fn test_truncated_packet_is_an_error() {
+        let error = Packet::parse("D2FE").unwrap_err();
+        assert!(error.message.starts_with("Packet ended unexpectedly"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,104 @@
+use std::ops::RangeInclusive;
+use aoc_common::parse::{parse_at, split_once_at};
+use aoc_common::{trace, ParseError, ParseResult, Solution};
+
+pub type Target = (RangeInclusive<i32>, RangeInclusive<i32>);
+
+pub struct Day17;
+impl Solution for Day17 {
+    type Input = Target;
+    type Answer1 = i32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Target> {
+        parse_target(input)
+    }
+
+    fn part1(target: &Target) -> i32 {
+        fire_probes(target).0
+    }
+
+    fn part2(target: &Target) -> u32 {
+        fire_probes(target).1
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<i32> {
+    Ok(Day17::part1(&Day17::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u32> {
+    Ok(Day17::part2(&Day17::parse(input)?))
+}
+
+// Tries every plausible initial velocity, returning the highest peak of any hit and the number of hits
+fn fire_probes(target: &Target) -> (i32, u32) {
+    let mut total_highest = 0;
+    let mut hit_counts = 0;
+    for initial_dx in 1..(target.0.end()+1) {
+        for initial_dy in *(target.1.start())..(-1*target.1.start() + 1) {
+            let mut pos = (0, 0);
+            let mut velocity = (initial_dx, initial_dy);
+            trace!("trial", "velocity {:?}", velocity);
+
+            let mut trial_highest = 0;
+            loop {
+                // Step
+                pos = (pos.0 + velocity.0, pos.1 + velocity.1);
+                velocity = (
+                    if velocity.0 > 0 { velocity.0 - 1 } else { 0 },
+                    velocity.1 - 1
+                );
+
+                trace!("probe_step", "pos: {:?}, vel: {:?}", pos, velocity);
+
+                // Track peak height
+                if pos.1 > trial_highest {
+                    trial_highest = pos.1;
+                }
+
+                // Check for a hit
+                if target.0.contains(&pos.0) && target.1.contains(&pos.1) {
+                    if trial_highest > total_highest {
+                        total_highest = trial_highest;
+                    }
+                    trace!("hit", "velocity {:?} hit, peaking at {}", (initial_dx, initial_dy), trial_highest);
+                    hit_counts += 1;
+                    break;
+                }
+
+                if &pos.0 > target.0.end() || &pos.1 < target.1.start() {
+                    break;
+                }
+            }
+        }
+    }
+
+    (total_highest, hit_counts)
+}
+
+// e.g. "target area: x=277..318, y=-92..-53"
+pub fn parse_target(input: &str) -> ParseResult<Target> {
+    let trimmed = input.trim();
+    let ranges = trimmed.strip_prefix("target area: ")
+        .ok_or_else(|| ParseError::at(trimmed, "Expected 'target area: ' prefix"))?;
+    let (x_range, y_range) = split_once_at(ranges, ", ", "target area")?;
+    let target = (parse_range(x_range, "x=")?, parse_range(y_range, "y=")?);
+    // The search assumes the probe has to travel right and fall down to reach the target
+    if *target.0.start() < 1 || *target.1.end() >= 0 {
+        return Err(ParseError::at(ranges, "The target must be to the right of and below the launcher"));
+    }
+    Ok(target)
+}
+
+fn parse_range(input: &str, prefix: &str) -> ParseResult<RangeInclusive<i32>> {
+    let range = input.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, format!("Expected '{}' prefix", prefix)))?;
+    let (from, to) = split_once_at(range, "..", "range")?;
+    let from: i32 = parse_at(from, "range start")?;
+    let to: i32 = parse_at(to, "range end")?;
+    if from > to {
+        return Err(ParseError::at(range, "Range start must not be after its end"));
+    }
+    Ok(from..=to)
+}
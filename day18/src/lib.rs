@@ -0,0 +1,402 @@
+use aoc_common::{ParseResult, Solution};
+pub use crate::snail_numbers::SnailNode;
+
+mod snail_numbers {
+    use aoc_common::ParseResult;
+    use crate::snail_numbers::explosive::Explosive;
+    use crate::snail_numbers::splittable::Splittable;
+
+    #[derive(PartialEq, Debug, Clone)]
+    pub enum SnailNode {
+        Literal(u32),
+        Pair(Box<SnailNode>, Box<SnailNode>),
+    }
+
+    impl SnailNode {
+        pub(crate) fn parse(input: &str) -> ParseResult<SnailNode> {
+            let mut cursor = parsing::Cursor::new(input);
+            let node = parsing::parse_pair(&mut cursor)?;
+            cursor.end()?;
+            Ok(node)
+        }
+
+        pub(crate) fn add(self, other: SnailNode) -> SnailNode {
+            let mut result = SnailNode::Pair(Box::new(self), Box::new(other));
+            result.reduce();
+            result
+        }
+
+        pub(crate) fn magnitude(&self) -> u32 {
+            match self {
+                SnailNode::Literal(v) => *v,
+                SnailNode::Pair(l, r) => {
+                    3 * l.magnitude() + 2 * r.magnitude()
+                }
+            }
+        }
+
+        fn reduce(&mut self) {
+            loop {
+                let result = self.explode(0);
+                if result.is_none() && !self.split() {
+                    // If we didn't explode and didn't split, we're fully reduced
+                    break;
+                }
+            }
+        }
+    }
+
+    pub(crate) mod explosive {
+        use crate::SnailNode;
+
+        pub(crate) trait Explosive {
+            fn explode(&mut self, depth: u8) -> Option<(Option<u32>, Option<u32>)>;
+        }
+
+        impl Explosive for SnailNode {
+            /*
+            Checks for explosion in this subtree
+
+            Returns Some((_,_)) if the subtree exploded, or None if not
+
+            The values within the Some tuple are the literal values to be added to the nearest literal to
+            the left and to the right respectively. They start as Some(l) and Some(r), but once consumed
+            (i.e. once added to a literal) they turn into None, to prevent them being added again further
+            up the tree.
+
+            Once a subtree has exploded, it will not try to explode any remaining portion of the tree.
+
+            Proceeds depth-first left-to-right.
+            */
+            fn explode(&mut self, depth: u8) -> Option<(Option<u32>, Option<u32>)> {
+                match self {
+                    SnailNode::Literal(_) => Option::None,
+                    SnailNode::Pair(left, right) => {
+                        if depth == 4 {
+                            // Swap out this node with a 0, return a Some result with left and right values to add
+                            // (Need to match again despite knowing it's a pair, now we've move the value out)
+                            match std::mem::replace(self, SnailNode::Literal(0)) {
+                                SnailNode::Literal(_) => panic!("Pair turned into literal"),
+                                SnailNode::Pair(left, right) => {
+                                    match (*left, *right) {
+                                        (SnailNode::Literal(lvalue), SnailNode::Literal(rvalue)) => {
+                                            Some((Some(lvalue), Some(rvalue)))
+                                        }
+                                        _ => panic!("Pair at depth 4 had non-literal children during explode")
+                                    }
+                                }
+                            }
+                        } else {
+                            // Check the left branch for explosion first.
+                            // If it does explode:
+                            //   - return the left-result up the chain (don't apply it immediately, that'll
+                            //     add it to the just-inserted 0)
+                            //   - add the right-result to the left-most literal on the right subtree
+                            //   - (by returning, implicitly avoid checking any further in the tree for
+                            //     explosion)
+                            // Else:
+                            //   - Check the right branch for explosion
+                            //   - If it does explode:
+                            //       - return the right-result up the chain
+                            //       - add the left-result to the right-most literal on the left subtree
+                            //   - Else the subtree from this node does not explode (so we return None)
+                            left.explode(depth + 1).and_then(|res| add_right(res, right))
+                                .or_else(|| right.explode(depth + 1).and_then(|res| add_left(res, left)))
+                        }
+                    }
+                }
+            }
+        }
+
+        trait ExplosivePrivate {
+            fn add_to_leftmost_literal(&mut self, value: u32);
+            fn add_to_rightmost_literal(&mut self, value: u32);
+        }
+        impl ExplosivePrivate for SnailNode {
+            fn add_to_leftmost_literal(&mut self, value: u32) {
+                match self {
+                    SnailNode::Literal(v) => {
+                        *v += value;
+                    }
+                    SnailNode::Pair(l, _) => {
+                        l.add_to_leftmost_literal(value);
+                    }
+                }
+            }
+
+            fn add_to_rightmost_literal(&mut self, value: u32) {
+                match self {
+                    SnailNode::Literal(v) => {
+                        *v += value;
+                    }
+                    SnailNode::Pair(_, r) => {
+                        r.add_to_rightmost_literal(value);
+                    }
+                }
+            }
+        }
+
+        fn add_left(result: (Option<u32>, Option<u32>), left: &mut Box<SnailNode>) -> Option<(Option<u32>, Option<u32>)> {
+            let (lresult, rresult) = result;
+            Some((
+                lresult.and_then(|value| {
+                    left.add_to_rightmost_literal(value);
+                    None
+                }),
+                rresult
+            ))
+        }
+
+        fn add_right(result: (Option<u32>, Option<u32>), right: &mut Box<SnailNode>) -> Option<(Option<u32>, Option<u32>)> {
+            let (lresult, rresult) = result;
+            Some((
+                lresult,
+                rresult.and_then(|value| {
+                    right.add_to_leftmost_literal(value);
+                    None
+                })
+            ))
+        }
+    }
+
+    pub(crate) mod splittable {
+        use crate::SnailNode;
+
+        pub(crate) trait Splittable {
+            fn split(&mut self) -> bool;
+        }
+
+        impl Splittable for SnailNode {
+            fn split(&mut self) -> bool {
+                match self {
+                    SnailNode::Literal(v) => {
+                        if *v >= 10 {
+                            let left = *v / 2;
+                            let right = *v - left;
+                            *self = SnailNode::Pair(Box::new(SnailNode::Literal(left)), Box::new(SnailNode::Literal(right)));
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    SnailNode::Pair(l, r) => {
+                        l.split() || r.split()
+                    }
+                }
+            }
+        }
+    }
+
+    mod parsing {
+        use std::iter::Peekable;
+        use std::str::CharIndices;
+        use aoc_common::{ParseError, ParseResult};
+        use crate::SnailNode;
+
+        pub(crate) struct Cursor<'a> {
+            input: &'a str,
+            chars: Peekable<CharIndices<'a>>,
+        }
+        impl <'a> Cursor<'a> {
+            pub(crate) fn new(input: &'a str) -> Cursor<'a> {
+                Cursor { input, chars: input.char_indices().peekable() }
+            }
+
+            // An error pointing at the next char (or the end of the input)
+            fn error(&mut self, message: &str) -> ParseError {
+                match self.chars.peek() {
+                    Some(&(i, c)) => ParseError::at(&self.input[i..i + c.len_utf8()], message),
+                    None => ParseError::at(&self.input[self.input.len()..], format!("{} but found the end of the line", message)),
+                }
+            }
+
+            pub(crate) fn end(&mut self) -> ParseResult<()> {
+                match self.chars.peek() {
+                    None => Ok(()),
+                    Some(_) => Err(self.error("Unexpected text after snail number")),
+                }
+            }
+        }
+
+        pub(crate) fn parse_pair(chars: &mut Cursor) -> ParseResult<SnailNode> {
+            consume('[', chars)?;
+            let left = parse_node(chars)?;
+            consume(',', chars)?;
+            let right = parse_node(chars)?;
+            consume(']', chars)?;
+            Ok(SnailNode::Pair(Box::new(left), Box::new(right)))
+        }
+
+        fn parse_node(chars: &mut Cursor) -> ParseResult<SnailNode> {
+            match chars.chars.peek() {
+                Some((_, '[')) => parse_pair(chars),
+                Some((_, '0'..='9')) => parse_literal(chars),
+                _ => Err(chars.error("Expected '[' or a digit")),
+            }
+        }
+
+        fn parse_literal(chars: &mut Cursor) -> ParseResult<SnailNode> {
+            let (_, num) = chars.chars.next().expect("Literal digit was peeked");
+            Ok(SnailNode::Literal(num.to_digit(10).expect("Literal digit was peeked")))
+        }
+
+        fn consume(expected: char, chars: &mut Cursor) -> ParseResult<()> {
+            match chars.chars.peek() {
+                Some(&(_, found)) if found == expected => {
+                    chars.chars.next();
+                    Ok(())
+                },
+                _ => Err(chars.error(&format!("Expected '{}'", expected))),
+            }
+        }
+    }
+}
+
+fn parse_lines(input: &str) -> ParseResult<Vec<SnailNode>> {
+    input.lines()
+        .map(SnailNode::parse)
+        .collect()
+}
+
+pub struct Day18;
+impl Solution for Day18 {
+    type Input = Vec<SnailNode>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Vec<SnailNode>> {
+        parse_lines(input)
+    }
+
+    fn part1(nums: &Vec<SnailNode>) -> u32 {
+        magnitude_of_sum(nums)
+    }
+
+    fn part2(nums: &Vec<SnailNode>) -> u32 {
+        max_magnitude_of_pair(nums)
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<u32> {
+    Ok(Day18::part1(&Day18::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<u32> {
+    Ok(Day18::part2(&Day18::parse(input)?))
+}
+
+fn magnitude_of_sum(nums: &[SnailNode]) -> u32 {
+    let sum = nums.iter().cloned()
+        .reduce(|acc, sn| acc.add(sn))
+        .expect("Could not sum");
+    sum.magnitude()
+}
+
+fn max_magnitude_of_pair(nums: &[SnailNode]) -> u32 {
+    let mut max_magnitude = 0;
+    for outer in nums.iter() {
+        for inner in nums.iter() {
+            if outer == inner {
+                continue;
+            }
+            let outer = outer.clone();
+            let inner = inner.clone();
+            let sum = outer.add(inner);
+            let magnitude = sum.magnitude();
+            if magnitude > max_magnitude {
+                max_magnitude = magnitude;
+            }
+        }
+    }
+    max_magnitude
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use crate::snail_numbers::splittable::Splittable;
+    use crate::snail_numbers::explosive::Explosive;
+    use super::*;
+
+    #[test]
+    fn test_simple_adding() {
+        let result = sn("[1,2]").add(sn("[[3,4],5]"));
+        assert_eq!(result, sn("[[1,2],[[3,4],5]]"));
+    }
+
+    #[test]
+    fn test_explode() {
+        assert_eq!(ex("[[[[[9,8],1],2],3],4]"), sn("[[[[0,9],2],3],4]"));
+        assert_eq!(ex("[7,[6,[5,[4,[3,2]]]]]"), sn("[7,[6,[5,[7,0]]]]"));
+        assert_eq!(ex("[[6,[5,[4,[3,2]]]],1]"), sn("[[6,[5,[7,0]]],3]"));
+        assert_eq!(ex("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]"), sn("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"));
+        assert_eq!(ex("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"), sn("[[3,[2,[8,0]]],[9,[5,[7,0]]]]"));
+    }
+
+    #[test]
+    fn test_split() {
+        let mut node = SnailNode::Pair(Box::new(SnailNode::Literal(10)), Box::new(SnailNode::Literal(0)));
+        let result = node.split();
+        assert_eq!((result, node), (true, sn("[[5,5],0]")));
+
+        let mut node = SnailNode::Pair(Box::new(SnailNode::Literal(11)), Box::new(SnailNode::Literal(0)));
+        let result = node.split();
+        assert_eq!((result, node), (true, sn("[[5,6],0]")));
+    }
+
+    #[test]
+    fn test_add_with_reduce() {
+        let result = sn("[[[[4,3],4],4],[7,[[8,4],9]]]").add(sn("[1,1]"));
+        assert_eq!(result, sn("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"));
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(sn("[9,1]").magnitude(), 29);
+        assert_eq!(sn("[1,9]").magnitude(), 21);
+        assert_eq!(sn("[[9,1],[1,9]]").magnitude(), 129);
+        assert_eq!(sn("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude(), 3488);
+    }
+
+    #[test]
+    fn test_parse_error_points_at_bad_char() {
+        let input = "[1,[2;3]]";
+        let error = SnailNode::parse(input).unwrap_err().locate(input);
+        assert_eq!(error.location.unwrap().column, 6);
+    }
+
+    proptest! {
+        #[test]
+        fn test_sums_are_fully_reduced(numbers in vec(aoc_gen::snail_number(), 2..8)) {
+            let sum = numbers.iter().map(|n| sn(n)).reduce(SnailNode::add).unwrap();
+            prop_assert!(depth(&sum) <= 4, "{:?} is nested too deeply", sum);
+            prop_assert!(max_literal(&sum) < 10, "{:?} has a literal that should have split", sum);
+        }
+    }
+
+    // How many pairs deep the most nested literal is
+    fn depth(node: &SnailNode) -> u32 {
+        match node {
+            SnailNode::Literal(_) => 0,
+            SnailNode::Pair(l, r) => 1 + depth(l).max(depth(r)),
+        }
+    }
+
+    fn max_literal(node: &SnailNode) -> u32 {
+        match node {
+            SnailNode::Literal(v) => *v,
+            SnailNode::Pair(l, r) => max_literal(l).max(max_literal(r)),
+        }
+    }
+
+    fn sn(input: &str) -> SnailNode {
+        SnailNode::parse(input).unwrap()
+    }
+
+    fn ex(input: &str) -> SnailNode {
+        let mut node = sn(input);
+        node.explode(0);
+        node
+    }
+}
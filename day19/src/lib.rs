@@ -0,0 +1,304 @@
+use std::collections::{HashMap, HashSet};
+use aoc_common::coord::Point;
+use aoc_common::parse::parse_at;
+use aoc_common::{ParseError, ParseResult, Solution};
+
+// The scanners' orientations, as rotations of the points they detect
+trait Rotation {
+    fn face_inverse_x(&self) -> Point;
+    fn face_inverse_y(&self) -> Point;
+    fn face_inverse_z(&self) -> Point;
+    fn rotate_around_x(&self) -> Point;
+    fn rotate_around_y(&self) -> Point;
+    fn rotate_around_z(&self) -> Point;
+    fn rotate_around_x_times(&self, times: u8) -> Point;
+    fn rotate_around_y_times(&self, times: u8) -> Point;
+    fn rotate_around_z_times(&self, times: u8) -> Point;
+    fn rotate_times(&self, times: u8, transform: fn(&Point) -> Point) -> Point;
+}
+
+impl Rotation for Point {
+    fn face_inverse_x(&self) -> Point {
+        self.rotate_around_y_times(2)
+    }
+    fn face_inverse_y(&self) -> Point {
+        self.rotate_around_z_times(2)
+    }
+    fn face_inverse_z(&self) -> Point {
+        self.rotate_around_x_times(2)
+    }
+
+    fn rotate_around_x(&self) -> Point {
+        Point { x: self.x, y: -self.z, z: self.y }
+    }
+    fn rotate_around_y(&self) -> Point {
+        Point { y: self.y, z: -self.x, x: self.z }
+    }
+    fn rotate_around_z(&self) -> Point {
+        Point { z: self.z, y: -self.x, x: self.y }
+    }
+    fn rotate_around_x_times(&self, times: u8) -> Point {
+        self.rotate_times(times, |p| p.rotate_around_x())
+    }
+    fn rotate_around_y_times(&self, times: u8) -> Point {
+        self.rotate_times(times, |p| p.rotate_around_y())
+    }
+    fn rotate_around_z_times(&self, times: u8) -> Point {
+        self.rotate_times(times, |p| p.rotate_around_z())
+    }
+    fn rotate_times(&self, times: u8, transform: fn(&Point) -> Point) -> Point {
+        assert!(times > 0 && times < 4);
+        let mut count = 1;
+        let mut result = transform(self);
+        while count < times {
+            result = transform(&result);
+            count += 1;
+        }
+        result
+    }
+}
+
+#[derive(Clone)]
+pub struct Scan {
+    scanner_id: usize,
+    points: Vec<Point>,
+}
+
+impl Scan {
+    fn transform<F>(&self, t: F) -> Scan where F: Fn(&Point) -> Point {
+        Scan {
+            scanner_id: self.scanner_id,
+            points: self.points.iter().map(t).collect()
+        }
+    }
+
+    fn translate(&self, point: &Point) -> Scan {
+        self.transform(|p| p.translate(point))
+    }
+
+    fn overlaps(&self, other: &Scan) -> Option<Point> {
+        let mut translation_counts = HashMap::<Point, u32>::new();
+        for other_point in &other.points {
+            for matched_point in &self.points {
+                let translation = matched_point.translation_needed_to(other_point);
+                let count = translation_counts.entry(translation).or_default();
+                *count += 1;
+                if *count >= 12 {
+                    let translation = matched_point.translation_needed_to(other_point);
+                    return Some(translation);
+                }
+            }
+        }
+        None
+    }
+}
+
+pub fn parse_scans(input: &str) -> ParseResult<Vec<Scan>> {
+    let scans = input.split("\n\n").map(parse_scanner_input).collect::<ParseResult<Vec<_>>>()?;
+    if scans.is_empty() {
+        return Err(ParseError::new("Expected at least one scanner"));
+    }
+    Ok(scans)
+}
+
+fn to_all_orientations(scans: &[Scan]) -> (Scan, HashMap<usize, Vec<Scan>>) {
+    let (scanner0_scan, others) = scans.split_first().expect("Could not get scanner 0's scan");
+
+    let scans = others.iter()
+        .map(|scan| (scan.scanner_id, transform_scan(scan.clone())))
+        .collect();
+
+    (scanner0_scan.clone(), scans)
+}
+
+// e.g. "--- scanner 0 ---", followed by one "x,y,z" line per beacon
+fn parse_scanner_input(input: &str) -> ParseResult<Scan> {
+    let mut iter = input.lines();
+    let top_line = iter.next().unwrap_or_default();
+    let id = top_line.strip_prefix("--- scanner ")
+        .and_then(|rest| rest.strip_suffix(" ---"))
+        .ok_or_else(|| ParseError::at(top_line, "Expected a header like '--- scanner 0 ---'"))?;
+    let id = parse_at(id, "scanner id")?;
+    let points = iter.map(|line| {
+        let parts = line.split(',')
+            .map(|part| parse_at(part, "coordinate"))
+            .collect::<ParseResult<Vec<_>>>()?;
+        if parts.len() != 3 {
+            return Err(ParseError::at(line, "Expected three coordinates"));
+        }
+        Ok(Point { x: parts[0], y: parts[1], z: parts[2] })
+    }).collect::<ParseResult<Vec<_>>>()?;
+
+    Ok(Scan { scanner_id: id, points })
+}
+
+fn transform_scan(base_scan: Scan) -> Vec<Scan> {
+    let transforms: Vec<fn(&Point) -> Point> = vec![
+        // +ve x
+        // |point| point,
+        |point| point.rotate_around_x_times(1),
+        |point| point.rotate_around_x_times(2),
+        |point| point.rotate_around_x_times(3),
+        // -ve x
+        |point| point.face_inverse_x(),
+        |point| point.face_inverse_x().rotate_around_x_times(1),
+        |point| point.face_inverse_x().rotate_around_x_times(2),
+        |point| point.face_inverse_x().rotate_around_x_times(3),
+        // -ve y
+        |point| point.rotate_around_z(),
+        |point| point.rotate_around_z().rotate_around_y_times(1),
+        |point| point.rotate_around_z().rotate_around_y_times(2),
+        |point| point.rotate_around_z().rotate_around_y_times(3),
+        // +ve y
+        |point| point.rotate_around_z().face_inverse_y(),
+        |point| point.rotate_around_z().face_inverse_y().rotate_around_y_times(1),
+        |point| point.rotate_around_z().face_inverse_y().rotate_around_y_times(2),
+        |point| point.rotate_around_z().face_inverse_y().rotate_around_y_times(3),
+        // -ve z
+        |point| point.rotate_around_y(),
+        |point| point.rotate_around_y().rotate_around_z_times(1),
+        |point| point.rotate_around_y().rotate_around_z_times(2),
+        |point| point.rotate_around_y().rotate_around_z_times(3),
+        // +ve z
+        |point| point.rotate_around_y().face_inverse_z(),
+        |point| point.rotate_around_y().face_inverse_z().rotate_around_z_times(1),
+        |point| point.rotate_around_y().face_inverse_z().rotate_around_z_times(2),
+        |point| point.rotate_around_y().face_inverse_z().rotate_around_z_times(3),
+    ];
+
+    let mut scans: Vec<Scan> = transforms.into_iter().map(|transform| {
+        base_scan.transform(transform)
+    }).collect();
+    scans.insert(0, base_scan);
+    scans
+}
+
+pub struct OceanMapper {
+    absolute_scans: Vec<(Point, Scan)>,
+    relative_scans_by_id: HashMap<usize, Vec<Scan>>,
+    unprocessed_absolute_scan_index_queue: Vec<usize>,
+}
+
+impl OceanMapper {
+    pub fn new(scans: &[Scan]) -> OceanMapper {
+        let (scanner_0_scan, relative_scans_by_id) = to_all_orientations(scans);
+
+        let absolute_scans = vec![(Point::origin(), scanner_0_scan)];
+        let unprocessed_absolute_scan_index_queue = vec![0];
+
+        OceanMapper {
+            absolute_scans,
+            relative_scans_by_id,
+            unprocessed_absolute_scan_index_queue,
+        }
+    }
+
+    pub fn triangulate_scanners(&mut self) {
+        while let Some(absolute_scan_index) = self.unprocessed_absolute_scan_index_queue.pop() {
+            let mut positioned_scanners = vec![];
+            {
+                let (_, absolute_scan) = self.absolute_scans.get(absolute_scan_index).unwrap();
+                for relative_scans in self.relative_scans_by_id.values() {
+                    for oriented_scan in relative_scans {
+                        if let Some(offset) = oriented_scan.overlaps(absolute_scan) {
+                            positioned_scanners.push((offset, oriented_scan.translate(&offset)));
+                            break;
+                        }
+                    }
+                }
+            }
+            while let Some(positioned_scan) = positioned_scanners.pop() {
+                self.relative_scans_by_id.remove(&positioned_scan.1.scanner_id);
+                self.absolute_scans.push(positioned_scan);
+                self.unprocessed_absolute_scan_index_queue.push(self.absolute_scans.len() - 1);
+            }
+        }
+    }
+
+    pub fn count_distinct_points(&self) -> usize {
+        let mut distinct_points = HashSet::new();
+        for (_, abs_scan) in &self.absolute_scans {
+            for p in &abs_scan.points {
+                distinct_points.insert(p);
+            }
+        }
+        distinct_points.len()
+    }
+
+    pub fn max_distance_between_scanners(&self) -> i32 {
+        let mut max_dist = 0;
+        for (pos_a, _) in &self.absolute_scans {
+            for (pos_b, _) in &self.absolute_scans {
+                let dist = pos_a.dist_to(pos_b);
+                max_dist = std::cmp::max(max_dist, dist);
+            }
+        }
+        max_dist
+    }
+}
+
+pub struct Day19;
+impl Solution for Day19 {
+    type Input = Vec<Scan>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> ParseResult<Vec<Scan>> {
+        parse_scans(input)
+    }
+
+    fn part1(scans: &Vec<Scan>) -> usize {
+        let mut ocean_mapper = OceanMapper::new(scans);
+        ocean_mapper.triangulate_scanners();
+        ocean_mapper.count_distinct_points()
+    }
+
+    fn part2(scans: &Vec<Scan>) -> i32 {
+        let mut ocean_mapper = OceanMapper::new(scans);
+        ocean_mapper.triangulate_scanners();
+        ocean_mapper.max_distance_between_scanners()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<usize> {
+    Ok(Day19::part1(&Day19::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<i32> {
+    Ok(Day19::part2(&Day19::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input;
+    use super::*;
+
+    fn parse_to_all_orientations(input: &str) -> (Scan, HashMap<usize, Vec<Scan>>) {
+        to_all_orientations(&parse_scans(input).unwrap())
+    }
+
+    #[test]
+    fn test_translated_required() {
+        let scanner_0_point = Point { x: -618, y: -824, z: -621};
+        let scanner_1_point = Point { x: 686, y: 422, z: 578 };
+        let scanner_1_scan = Scan { scanner_id: 1, points: vec![scanner_1_point] };
+        let scanner_1_scans = transform_scan(scanner_1_scan);
+
+        for scan in scanner_1_scans {
+            let point = scan.points[0];
+            let trans = point.translation_needed_to(&scanner_0_point);
+            println!("{:?} => {:?}", point, trans);
+        }
+    }
+
+    #[test]
+    fn test_overlapping_example_0_and_1() {
+        let example_input = input::read_to_string("example");
+        let (scan_0, scans) = parse_to_all_orientations(&example_input);
+        for scan_1 in &scans[&1] {
+            if let Some(trans) = scan_1.overlaps(&scan_0) {
+                println!("{:?}", trans);
+            }
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use aoc_common::coord::Coord;
+use aoc_common::render::{Animation, Frame};
+use aoc_common::{ParseError, ParseResult, Solution};
+
+trait Widenable {
+    fn widen_by(&self, delta: isize) -> Self;
+}
+impl Widenable for RangeInclusive<isize> {
+    fn widen_by(&self, delta: isize) -> Self {
+        (self.start() - delta)..=(self.end() + delta)
+    }
+}
+
+#[derive(Clone)]
+struct Bounds {
+    x_range: RangeInclusive<isize>,
+    y_range: RangeInclusive<isize>,
+}
+impl Bounds {
+    fn grow(&self) -> Bounds {
+        Bounds {
+            x_range: self.x_range.widen_by(1),
+            y_range: self.y_range.widen_by(1),
+        }
+    }
+
+    fn contains(&self, coord: &Coord) -> bool {
+        self.x_range.contains(&coord.x) && self.y_range.contains(&coord.y)
+    }
+}
+
+#[derive(Clone)]
+pub struct Image {
+    bounds: Bounds,
+    lit_pixels: HashSet<Coord>,
+    bg_is_lit: bool,
+}
+impl Image {
+    fn parse(input: &str) -> ParseResult<Image> {
+        check_pixels(input)?;
+        let width = input.lines().next().map_or(0, |line| line.len());
+        if let Some(line) = input.lines().find(|line| line.len() != width) {
+            return Err(ParseError::at(line, format!("Expected {} pixels, as on the first line", width)));
+        }
+
+        let lit_pixels = input.lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.bytes().enumerate().filter_map(move |(x, c)| {
+                    if c == b'#' {
+                        Some(Coord::new(x as isize, y as isize))
+                    } else {
+                        None
+                    }
+                })
+            })
+            .fold(HashSet::new(), |mut acc, coords| {
+                coords.for_each(|c| {
+                    acc.insert(c);
+                });
+                acc
+            });
+        let max_y = input.lines().count() as isize - 1;
+        let max_x = width as isize - 1;
+
+        Ok(Image {
+            bounds: Bounds { x_range: 0..=max_x, y_range: 0..=max_y },
+            lit_pixels,
+            bg_is_lit: false,
+        })
+    }
+
+    fn step(&self, enh_alg: &Vec<bool>) -> Image {
+        let new_bounds = self.bounds.grow();
+
+        let mut new_lit_pixels = HashSet::new();
+        for x in new_bounds.x_range.clone() {
+            for y in new_bounds.y_range.clone() {
+                let coord = Coord::new(x, y);
+                let neighbourhood_num = self.num_from_pixel_neighbourhood(&coord);
+                let should_be_lit = enh_alg.is_pixel_lit(neighbourhood_num);
+                if should_be_lit {
+                    new_lit_pixels.insert(coord);
+                }
+            }
+        }
+
+        let out_of_bounds = Coord::new(self.bounds.x_range.start() - 10, self.bounds.y_range.start() - 10);
+        let oob_neighbourhood_num = self.num_from_pixel_neighbourhood(&out_of_bounds);
+        let new_bg_is_lit = enh_alg.is_pixel_lit(oob_neighbourhood_num);
+        
+        Image {
+            bounds: new_bounds,
+            lit_pixels: new_lit_pixels,
+            bg_is_lit: new_bg_is_lit,
+        }
+    }
+
+    fn is_pixel_lit(&self, coord: &Coord) -> bool {
+        if self.bounds.contains(coord) {
+            self.lit_pixels.contains(coord)
+        } else {
+            self.bg_is_lit
+        }
+    }
+
+    fn num_from_pixel(&self, coord: &Coord) -> usize {
+        if self.is_pixel_lit(coord) {
+            1
+        } else {
+            0
+        }
+    }
+
+    fn num_from_pixel_neighbourhood(&self, coord: &Coord) -> usize {
+        let offsets = [(-1, -1), (0, -1), (1, -1), (-1, 0), (0, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+        offsets.map(|(dx, dy)| coord.offset(dx, dy))
+            .map(|c| self.num_from_pixel(&c))
+            .into_iter()
+            .fold(0, |acc, i| {
+                acc * 2 + i
+            })
+    }
+
+    // Draws the image within the given bounds, which may extend beyond its own
+    fn frame(&self, bounds: &Bounds) -> Frame {
+        let width = (bounds.x_range.end() - bounds.x_range.start() + 1) as usize;
+        let height = (bounds.y_range.end() - bounds.y_range.start() + 1) as usize;
+        let mut frame = Frame::new(width, height, 2);
+        for (fy, y) in bounds.y_range.clone().enumerate() {
+            for (fx, x) in bounds.x_range.clone().enumerate() {
+                if self.is_pixel_lit(&Coord::new(x, y)) {
+                    frame.set(fx, fy, 1);
+                }
+            }
+        }
+        frame
+    }
+
+    #[allow(dead_code)]
+    fn as_debug_string(&self, xrange: RangeInclusive<isize>, yrange: RangeInclusive<isize>) -> String {
+        let mut result = String::new();
+        for y in yrange.clone() {
+            for x in xrange.clone() {
+                let coord = Coord::new(x, y);
+                let char = if self.is_pixel_lit(&coord) { '#' } else { '.' };
+                result.push(char);
+            }
+            if &y < yrange.end() {
+                result.push('\n');
+            }
+        }
+        result
+    }
+}
+
+trait ImageEnhancementAlgorithm {
+    fn is_pixel_lit(&self, index: usize) -> bool;
+}
+impl ImageEnhancementAlgorithm for Vec<bool> {
+    fn is_pixel_lit(&self, index: usize) -> bool {
+        self[index]
+    }
+}
+fn parse_image_enhancement_algorithm(input: &str) -> ParseResult<Vec<bool>> {
+    check_pixels(input)?;
+    if input.len() != 512 {
+        return Err(ParseError::at(input, format!("Expected 512 pixels in the algorithm, found {}", input.len())));
+    }
+    Ok(input.bytes().map(|c| c == b'#').collect())
+}
+
+fn check_pixels(input: &str) -> ParseResult<()> {
+    match input.char_indices().find(|&(_, c)| !matches!(c, '#' | '.' | '\n')) {
+        Some((i, c)) => Err(ParseError::at(&input[i..i + c.len_utf8()], "Expected '#' or '.'")),
+        None => Ok(()),
+    }
+}
+
+pub fn parse_input(input: &str) -> ParseResult<(Vec<bool>, Image)> {
+    let (alg_input, image_input) = input.split_once("\n\n")
+        .ok_or_else(|| ParseError::new("Expected a blank line between the algorithm and the image"))?;
+    let enh_alg = parse_image_enhancement_algorithm(alg_input)?;
+    let image = Image::parse(image_input)?;
+    Ok((enh_alg, image))
+}
+
+pub struct Day20;
+impl Solution for Day20 {
+    type Input = (Vec<bool>, Image);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> ParseResult<(Vec<bool>, Image)> {
+        parse_input(input)
+    }
+
+    fn part1((enh_alg, image): &(Vec<bool>, Image)) -> usize {
+        count_lit_after(enh_alg, image, 2)
+    }
+
+    fn part2((enh_alg, image): &(Vec<bool>, Image)) -> usize {
+        count_lit_after(enh_alg, image, 50)
+    }
+}
+
+// Every step is drawn within the final image's bounds, so the frames are all the same size
+impl Animation for Day20 {
+    fn frames((enh_alg, image): &(Vec<bool>, Image)) -> Vec<Frame> {
+        let mut images = vec![image.clone()];
+        for _ in 1..=50 {
+            images.push(images[images.len() - 1].step(enh_alg));
+        }
+        let bounds = images[images.len() - 1].bounds.clone();
+        images.iter().map(|image| image.frame(&bounds)).collect()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<usize> {
+    Ok(Day20::part1(&Day20::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<usize> {
+    Ok(Day20::part2(&Day20::parse(input)?))
+}
+
+fn count_lit_after(enh_alg: &Vec<bool>, image: &Image, steps: usize) -> usize {
+    let mut image = image.clone();
+    for _ in 1..=steps {
+        image = image.step(enh_alg);
+    }
+    image.lit_pixels.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input;
+    use super::*;
+
+    fn parse_input_file(path: &str) -> (Vec<bool>, Image) {
+        parse_input(&input::read_to_string(path)).unwrap()
+    }
+
+    #[test]
+    fn test_example_input_centre_pixel_neighbourhood_num() {
+        let (_, image) = parse_input_file("example");
+        let centre = Coord::new(2, 2);
+        let num = image.num_from_pixel_neighbourhood(&centre);
+        assert_eq!(num, 34);
+    }
+
+    #[test]
+    fn test_example_input_centre_pixel_is_lit_next_step() {
+        let (enh_alg, _) = parse_input_file("example");
+        assert!(enh_alg.is_pixel_lit(34));
+    }
+
+    #[test]
+    fn test_example_pixels_that_should_light_after_step() {
+        let (enh_alg, image) = parse_input_file("example");
+
+        let coord = Coord::new(-1, 0);
+        let num = image.num_from_pixel_neighbourhood(&coord);
+        let will_be_lit = enh_alg.is_pixel_lit(num);
+        assert!(will_be_lit);
+
+        let coord = Coord::new(0, -1);
+        let num = image.num_from_pixel_neighbourhood(&coord);
+        let will_be_lit = enh_alg.is_pixel_lit(num);
+        assert!(will_be_lit);
+    }
+
+    #[test]
+    fn test_example_pixels_are_lit_after_step() {
+        let (enh_alg, image) = parse_input_file("example");
+        let image = image.step(&enh_alg);
+
+        let coord = Coord::new(-1, 0);
+        let is_lit = image.is_pixel_lit(&coord);
+        assert!(is_lit);
+
+        let coord = Coord::new(0, -1);
+        let is_lit = image.is_pixel_lit(&coord);
+        assert!(is_lit);
+    }
+
+    #[test]
+    fn test_debug_string() {
+        let (_, image) = parse_input_file("example");
+        let expected = "#..#.
+#....
+##..#
+..#..
+..###";
+        assert_eq!(image.as_debug_string(0..=4, 0..=4), expected);
+    }
+
+    #[test]
+    fn test_example_after_one_step() {
+        let (enh_alg, image) = parse_input_file("example");
+        let image = image.step(&enh_alg);
+        let expected = "...............
+...............
+...............
+...............
+.....##.##.....
+....#..#.#.....
+....##.#..#....
+....####..#....
+.....#..##.....
+......##..#....
+.......#.#.....
+...............
+...............
+...............
+...............";
+        assert_eq!(image.as_debug_string(-5..=9, -5..=9), expected);
+    }
+
+    #[test]
+    fn test_example_lit_pixels_after_two_steps() {
+        let (enh_alg, image) = parse_input_file("example");
+        let new_image = image.step(&enh_alg).step(&enh_alg);
+        assert_eq!(new_image.lit_pixels.len(), 35);
+    }
+}
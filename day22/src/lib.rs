@@ -0,0 +1,360 @@
+use std::ops::RangeInclusive;
+use aoc_common::parse::{parse_at, split_once_at};
+use aoc_common::{ParseError, ParseResult, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Cuboid {
+    x_range: RangeInclusive<i32>,
+    y_range: RangeInclusive<i32>,
+    z_range: RangeInclusive<i32>,
+}
+impl Cuboid {
+    // e.g. "x=-20..26,y=-36..17,z=-47..7"
+    fn from(input: &str) -> ParseResult<Cuboid> {
+        let parts = input.split(',').collect::<Vec<_>>();
+        if parts.len() != 3 {
+            return Err(ParseError::at(input, "Expected x, y and z ranges"));
+        }
+        let mut ranges = parts.into_iter()
+            .zip(["x=", "y=", "z="])
+            .map(|(r, prefix)| {
+                let r = r.strip_prefix(prefix)
+                    .ok_or_else(|| ParseError::at(r, format!("Expected '{}' prefix", prefix)))?;
+                let (from, to) = split_once_at(r, "..", "range")?;
+                let from: i32 = parse_at(from, "range start")?;
+                let to: i32 = parse_at(to, "range end")?;
+                if from > to {
+                    return Err(ParseError::at(r, "Range start must not be after its end"));
+                }
+                Ok(from..=to)
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+        Ok(Cuboid {
+            x_range: ranges.remove(0),
+            y_range: ranges.remove(0),
+            z_range: ranges.remove(0),
+        })
+    }
+
+    fn clone_from(x_range: &RangeInclusive<i32>, y_range: &RangeInclusive<i32>, z_range: &RangeInclusive<i32>) -> Cuboid {
+        Cuboid {
+            x_range: x_range.clone(),
+            y_range: y_range.clone(),
+            z_range: z_range.clone(),
+        }
+    }
+
+    fn overlaps_with(&self, other: &Cuboid) -> bool {
+        ranges_overlap(&self.x_range, &other.x_range) &&
+        ranges_overlap(&self.y_range, &other.y_range) &&
+        ranges_overlap(&self.z_range, &other.z_range)
+    }
+
+    fn is_contained_by(&self, other: &Cuboid) -> bool {
+        other.x_range.contains(self.x_range.start()) && other.x_range.contains(self.x_range.end()) &&
+        other.y_range.contains(self.y_range.start()) && other.y_range.contains(self.y_range.end()) &&
+        other.z_range.contains(self.z_range.start()) && other.z_range.contains(self.z_range.end())
+    }
+
+    fn subtract(self, other: &Cuboid, output: &mut Vec<Cuboid>) {
+        // If this cuboid and the other don't intersect, then this cuboid won't be split, and can
+        // push itself onto the output without modification
+        if !self.overlaps_with(other) {
+            output.push(self);
+            return;
+        }
+
+        let x_subranges = [
+            (0, range_before(&self.x_range, &other.x_range)),
+            (1, range_overlap(&self.x_range, &other.x_range)),
+            (2, range_after(&self.x_range, &other.x_range)),
+        ];
+        let y_subranges = [
+            (0, range_before(&self.y_range, &other.y_range)),
+            (1, range_overlap(&self.y_range, &other.y_range)),
+            (2, range_after(&self.y_range, &other.y_range)),
+        ];
+        let z_subranges = [
+            (0, range_before(&self.z_range, &other.z_range)),
+            (1, range_overlap(&self.z_range, &other.z_range)),
+            (2, range_after(&self.z_range, &other.z_range)),
+        ];
+
+        for (x_op, x_subrange) in &x_subranges {
+            if let Some(new_x) = x_subrange {
+                for (y_op, y_subrange) in &y_subranges {
+                    if let Some(new_y) = y_subrange {
+                        for (z_op, z_subrange) in &z_subranges {
+                            if x_op != &1 || y_op != &1 || z_op != &1 {
+                                if let Some(new_z) = z_subrange {
+                                    output.push(Cuboid::clone_from(new_x, new_y, new_z));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn volume(&self) -> usize {
+        (self.x_range.end() - self.x_range.start() + 1) as usize *
+            (self.y_range.end() - self.y_range.start() + 1) as usize *
+            (self.z_range.end() - self.z_range.start() + 1) as usize
+    }
+}
+
+fn ranges_overlap(range: &RangeInclusive<i32>, other: &RangeInclusive<i32>) -> bool {
+    range.start() <= other.end() && range.end() >= other.start()
+}
+
+fn range_before(range: &RangeInclusive<i32>, other: &RangeInclusive<i32>) -> Option<RangeInclusive<i32>> {
+    if range.start() < other.start() {
+        let new_end = std::cmp::min(*range.end(), other.start() - 1);
+        Some(*range.start()..=new_end)
+    } else {
+        None
+    }
+}
+
+fn range_overlap(range: &RangeInclusive<i32>, other: &RangeInclusive<i32>) -> Option<RangeInclusive<i32>> {
+    if ranges_overlap(range, other) {
+        let overlap_start = std::cmp::max(range.start(), other.start());
+        let overlap_end = std::cmp::min(range.end(), other.end());
+        Some(*overlap_start..=*overlap_end)
+    } else {
+        None
+    }
+}
+
+fn range_after(range: &RangeInclusive<i32>, other: &RangeInclusive<i32>) -> Option<RangeInclusive<i32>> {
+    if range.end() > other.end() {
+        let new_start = std::cmp::max(other.end() + 1, *range.start());
+        Some(new_start..=*range.end())
+    } else {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    is_on: bool,
+    cuboid: Cuboid,
+}
+impl Instruction {
+    pub fn from(input: &str) -> ParseResult<Instruction> {
+        let (is_on, ranges) = split_once_at(input, " ", "instruction")?;
+        let is_on = match is_on {
+            "on" => true,
+            "off" => false,
+            _ => return Err(ParseError::at(is_on, "Expected 'on' or 'off'")),
+        };
+        let cuboid = Cuboid::from(ranges)?;
+        Ok(Instruction {
+            is_on,
+            cuboid
+        })
+    }
+}
+
+struct ReactorCore {
+    on_cuboids: Vec<Cuboid>,
+}
+impl ReactorCore {
+    fn new() -> ReactorCore {
+        ReactorCore {
+            on_cuboids: vec![],
+        }
+    }
+
+    fn initialise(&mut self, instructions: &[Instruction]) {
+        let initialisation_area = Cuboid { x_range: -50..=50, y_range: -50..=50, z_range: -50..=50 };
+        let init_instructions = instructions.iter()
+            .filter(|i| i.cuboid.is_contained_by(&initialisation_area));
+        for instruction in init_instructions {
+            self.process(instruction);
+        }
+    }
+
+    fn reboot(&mut self, instructions: &[Instruction]) {
+        for instruction in instructions {
+            self.process(instruction);
+        }
+    }
+
+    fn process(&mut self, instruction: &Instruction) {
+        let old_cuboids = std::mem::take(&mut self.on_cuboids);
+        for cuboid in old_cuboids {
+            cuboid.subtract(&instruction.cuboid, &mut self.on_cuboids);
+        }
+        if instruction.is_on {
+            self.on_cuboids.push(instruction.cuboid.clone())
+        }
+    }
+
+    fn count_on_cubes(&self) -> usize {
+        self.on_cuboids.iter().map(|c| c.volume()).sum()
+    }
+}
+
+pub struct Day22;
+impl Solution for Day22 {
+    type Input = Vec<Instruction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> ParseResult<Vec<Instruction>> {
+        input.lines().map(Instruction::from).collect()
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> usize {
+        let mut reactor_core = ReactorCore::new();
+        reactor_core.initialise(instructions);
+        reactor_core.count_on_cubes()
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> usize {
+        let mut reactor_core = ReactorCore::new();
+        reactor_core.reboot(instructions);
+        reactor_core.count_on_cubes()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<usize> {
+    Ok(Day22::part1(&Day22::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<usize> {
+    Ok(Day22::part2(&Day22::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input;
+    use proptest::prelude::*;
+    use super::*;
+
+    #[test]
+    fn test_cuboid_volume() {
+        let cuboid = Cuboid {
+            x_range: -1..=1,
+            y_range: -1..=1,
+            z_range: -1..=1,
+        };
+        assert_eq!(cuboid.volume(), 27);
+    }
+
+    #[test]
+    fn test_subtracting_wholly_overlapped_cube() {
+        let bigger = Cuboid {
+            x_range: -1..=1,
+            y_range: -1..=1,
+            z_range: -1..=1,
+        };
+        let smaller = Cuboid {
+            x_range: 0..=0,
+            y_range: 0..=0,
+            z_range: 0..=0,
+        };
+        let mut results = vec![];
+        bigger.subtract(&smaller, &mut results);
+        let volume: usize = results.iter().map(|c| c.volume()).sum();
+        assert_eq!(volume, 26);
+    }
+
+    #[test]
+    fn test_subtracting_wholly_overlapping_cube() {
+        let bigger = Cuboid {
+            x_range: -1..=1,
+            y_range: -1..=1,
+            z_range: -1..=1,
+        };
+        let smaller = Cuboid {
+            x_range: 0..=0,
+            y_range: 0..=0,
+            z_range: 0..=0,
+        };
+        let mut results = vec![];
+        smaller.subtract(&bigger, &mut results);
+        let volume: usize = results.iter().map(|c| c.volume()).sum();
+        assert_eq!(volume, 0);
+    }
+
+    #[test]
+    fn test_subtracting_partially_overlapping_cube() {
+        let first = Cuboid {
+            x_range: -1..=1,
+            y_range: -1..=1,
+            z_range: -1..=1,
+        };
+        let second = Cuboid {
+            x_range: 0..=2,
+            y_range: 0..=2,
+            z_range: 0..=2,
+        };
+        let mut results = vec![];
+        first.subtract(&second, &mut results);
+        let volume: usize = results.iter().map(|c| c.volume()).sum();
+        assert_eq!(volume, 19);
+    }
+
+    #[test]
+    fn test_non_overlapping_cube() {
+        let first = Cuboid {
+            x_range: -1..=1,
+            y_range: -1..=1,
+            z_range: -1..=1,
+        };
+        let second = Cuboid {
+            x_range: 2..=3,
+            y_range: 2..=3,
+            z_range: 2..=3,
+        };
+        let mut results = vec![];
+        first.subtract(&second, &mut results);
+        let volume: usize = results.iter().map(|c| c.volume()).sum();
+        assert_eq!(volume, 27);
+    }
+
+    #[test]
+    fn test_subtracting_first_two_instructions() {
+        let first = Cuboid {
+            x_range: -44..=9,
+            y_range: -9..=44,
+            z_range: -34..=13,
+        };
+        assert_eq!(first.volume(), 139968);
+        let second = Cuboid {
+            x_range: -42..=11,
+            y_range: -16..=33,
+            z_range: -2..=48,
+        };
+        let mut results = vec![];
+        first.subtract(&second, &mut results);
+        assert_eq!(results.len(), 7);
+        let volume: usize = results.iter().map(|c| c.volume()).sum();
+        assert_eq!(volume, 104192);
+    }
+
+    #[test]
+    fn test_example_1_part_1() {
+        let input = input::read_to_string("example1");
+        let instructions = input.lines().map(Instruction::from).collect::<ParseResult<Vec<_>>>().unwrap();
+        let mut reactor_core = ReactorCore::new();
+        reactor_core.initialise(&instructions);
+        assert_eq!(reactor_core.count_on_cubes(), 39);
+    }
+
+    proptest! {
+        #[test]
+        fn test_disjoint_steps_can_be_reordered(
+            (steps, reordered) in aoc_gen::day22_disjoint_steps()
+                .prop_flat_map(|steps| (Just(steps.clone()), Just(steps).prop_shuffle()))
+        ) {
+            let instructions = |steps: &[String]| Day22::parse(&steps.join("\n")).unwrap();
+            let (instructions, reordered) = (instructions(&steps), instructions(&reordered));
+            prop_assert_eq!(Day22::part1(&instructions), Day22::part1(&reordered));
+            prop_assert_eq!(Day22::part2(&instructions), Day22::part2(&reordered));
+        }
+    }
+}
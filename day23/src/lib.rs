@@ -0,0 +1,459 @@
+use std::fmt;
+use crate::AmphipodVariety::{A, B, C, D};
+use crate::BurrowLocation::{Hallway, Room};
+use aoc_common::search::{dijkstra, SearchProblem};
+use aoc_common::{trace, ParseError, ParseResult, Solution};
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub enum AmphipodVariety {
+    A,
+    B,
+    C,
+    D,
+}
+impl AmphipodVariety {
+    fn from_char(c: char) -> Option<AmphipodVariety> {
+        match c {
+            'A' => Some(A),
+            'B' => Some(B),
+            'C' => Some(C),
+            'D' => Some(D),
+            _ => None,
+        }
+    }
+
+    fn room_index(&self) -> usize {
+        match self {
+            AmphipodVariety::A => 0,
+            AmphipodVariety::B => 1,
+            AmphipodVariety::C => 2,
+            AmphipodVariety::D => 3,
+        }
+    }
+
+    fn movement_cost(&self) -> usize {
+        match self {
+            AmphipodVariety::A => 1,
+            AmphipodVariety::B => 10,
+            AmphipodVariety::C => 100,
+            AmphipodVariety::D => 1000,
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            A => "A",
+            B => "B",
+            C => "C",
+            D => "D",
+        }
+    }
+}
+
+#[derive(Clone, Hash, Eq, PartialEq)]
+struct Amphipod {
+    variety: AmphipodVariety,
+    is_home: bool,
+}
+
+fn debug_char(amph: &Option<Amphipod>) -> &str {
+    match amph {
+        None => ".",
+        Some(amph) => amph.variety.name()
+    }
+}
+
+enum BurrowLocation {
+    Room(usize, usize), // (index of room, index of space within room, top to bottom)
+    Hallway(usize), // (index of space in hallway, left to right)
+}
+
+type Move = (BurrowLocation, BurrowLocation, usize);
+
+// Rearranging the amphipods, from any burrow to one where they're all home
+struct Rearrangement<const DEPTH: usize>;
+impl<const DEPTH: usize> SearchProblem for Rearrangement<DEPTH> {
+    type State = Burrow<DEPTH>;
+    type Cost = usize;
+
+    fn neighbours(&self, burrow: &Burrow<DEPTH>) -> Vec<(Burrow<DEPTH>, usize)> {
+        burrow.available_moves()
+            .iter()
+            .map(|mv| {
+                let mut new_burrow = burrow.clone();
+                let move_cost = new_burrow.apply_move(mv);
+                (new_burrow, move_cost)
+            })
+            .collect()
+    }
+
+    fn is_goal(&self, burrow: &Burrow<DEPTH>) -> bool {
+        burrow.is_complete()
+    }
+}
+
+#[derive(Clone, Hash, Eq, PartialEq)]
+struct Burrow<const DEPTH: usize> {
+    rooms: [[Option<Amphipod>; DEPTH]; 4],
+    hallway: [Option<Amphipod>; 11],
+}
+
+impl<const DEPTH: usize> Burrow<DEPTH> {
+    fn new(rooms: [[AmphipodVariety; DEPTH]; 4]) -> Burrow<DEPTH> {
+        let mut rooms = rooms.map(|r| {
+            r.map(|variety| {
+                Some(Amphipod { variety, is_home: false })
+            })
+        });
+        for (ri, room) in rooms.iter_mut().enumerate() {
+            let mut lower_are_all_home = true;
+            for si in 0..room.len() {
+                let si = room.len() - si - 1;
+                if let Some(amph) = &mut room[si] {
+                    amph.is_home = lower_are_all_home && ri == amph.variety.room_index();
+                    lower_are_all_home = amph.is_home;
+                }
+            }
+        }
+        Burrow {
+            rooms,
+            hallway: [None, None, None, None, None, None, None, None, None, None, None],
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        const EXPECTED_VARIETY_BY_ROOM_ID: [AmphipodVariety; 4] = [AmphipodVariety::A, AmphipodVariety::B, AmphipodVariety::C, AmphipodVariety::D];
+        for (room, expected_variety) in self.rooms.iter().zip(EXPECTED_VARIETY_BY_ROOM_ID.iter()) {
+            for amph in room {
+                if let Some(amph) = amph {
+                    if &amph.variety != expected_variety {
+                        // Mismatched variety
+                        return false;
+                    }
+                } else {
+                    // Empty room
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    // List of valid (from_location, to_location, spaces_moved) tuples
+    fn available_moves(&self) -> Vec<Move> {
+        let mut result = vec![];
+
+        // Find amphs in hallway that can move to their destination room
+        let hallway_moves = self.hallway.iter()
+            .enumerate()
+            .filter_map(|(i, maybe_amph)| {
+                maybe_amph.as_ref().map(|amph| (i, amph))
+            })
+            .filter_map(|(i, amph)| {
+                let ri = amph.variety.room_index();
+                let maybe_si = self.deepest_available_slot_index(&ri);
+                if let Some(si) = maybe_si {
+                    // There's a slot free. Now check if hallway is free
+                    let target_hi = self.hallway_index_of_room(&ri);
+                    let (low, high) = if target_hi < i {
+                        (target_hi, i - 1)
+                    } else {
+                        (i + 1, target_hi)
+                    };
+                    let mut range = low..=high;
+                    let all_free = range.all(|hi| self.hallway[hi].is_none());
+                    if all_free {
+                        let from = Hallway(i);
+                        let to = Room(ri, si);
+                        let moves = high - low + 1 + si + 1;
+                        Some((from, to, moves))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            });
+        result.extend(hallway_moves);
+
+        // find hallway spots amphs in rooms other than their own can move to
+        for (ri, room) in self.rooms.iter().enumerate() {
+            let first_amph = room.iter().enumerate()
+                .find(|(_, slot)| slot.is_some());
+            if let Some((si, Some(amph))) = first_amph {
+                if !amph.is_home {
+                    for target_hi in [0, 1, 3, 5, 7, 9, 10] {
+                        let starting_hi = self.hallway_index_of_room(&ri);
+                        let low = std::cmp::min(starting_hi, target_hi);
+                        let high = std::cmp::max(starting_hi, target_hi);
+                        let mut range = low..=high;
+                        let is_clear = range.all(|hi| self.hallway[hi].is_none());
+                        if is_clear {
+                            let from = Room(ri, si);
+                            let to = Hallway(target_hi);
+                            let moves = high - low + si + 1;
+                            result.push((from, to, moves));
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    // Returns cost of the move
+    fn apply_move(&mut self, mv: &Move) -> usize {
+        // Move out of from
+        let amph = match mv.0 {
+            Room(ri, si) => {
+                self.rooms[ri][si].take()
+            },
+            Hallway(hi) => {
+                self.hallway[hi].take()
+            }
+        };
+
+        let mut amph = amph.expect("Move's from location is empty");
+
+        let cost = amph.variety.movement_cost() * mv.2;
+
+        // Move into to
+        match mv.1 {
+            Room(ri, si) => {
+                if self.rooms[ri][si].is_some() {
+                    panic!("Move's to location is not empty");
+                }
+                amph.is_home = true;
+                self.rooms[ri][si] = Some(amph);
+            },
+            Hallway(hi) => {
+                if self.hallway[hi].is_some() {
+                    panic!("Move's to location is not empty");
+                }
+                self.hallway[hi] = Some(amph);
+            }
+        }
+
+        cost
+    }
+
+    fn deepest_available_slot_index(&self, &room_index: &usize) -> Option<usize> {
+        let room = &self.rooms[room_index];
+        for si in 0..room.len() {
+            let si = room.len() - si - 1;
+            if let Some(amph) = &room[si] {
+                if !amph.is_home {
+                    // Even if there's a free slow higher up, this amph still needs to move, so
+                    // this room cannot be a destination
+                    return None;
+                }
+            } else {
+                // This slot is empty
+                return Some(si);
+            }
+        }
+        // If we reached the top without finding a slot, this room isn't a destination
+        None
+    }
+
+    fn hallway_index_of_room(&self, room_index: &usize) -> usize {
+        match room_index {
+            0 => 2,
+            1 => 4,
+            2 => 6,
+            3 => 8,
+            _ => unreachable!("Unknown room index")
+        }
+    }
+}
+
+impl<const DEPTH: usize> fmt::Display for Burrow<DEPTH> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "#############")?;
+        write!(f, "#")?;
+        for h in &self.hallway {
+            write!(f, "{}", debug_char(h))?;
+        }
+        writeln!(f, "#")?;
+        writeln!(
+            f,
+            "###{}#{}#{}#{}###",
+            debug_char(&self.rooms[0][0]),
+            debug_char(&self.rooms[1][0]),
+            debug_char(&self.rooms[2][0]),
+            debug_char(&self.rooms[3][0]),
+        )?;
+        for i in 1..DEPTH {
+            writeln!(
+                f,
+                "  #{}#{}#{}#{}#",
+                debug_char(&self.rooms[0][i]),
+                debug_char(&self.rooms[1][i]),
+                debug_char(&self.rooms[2][i]),
+                debug_char(&self.rooms[3][i]),
+            )?;
+        }
+        write!(f, "  #########")
+    }
+}
+
+// Reads the amphipods' starting rooms from the burrow diagram, e.g.
+// #############
+// #...........#
+// ###A#D#C#A###
+//   #C#D#B#B#
+//   #########
+pub fn parse_rooms(input: &str) -> ParseResult<[[AmphipodVariety; 2]; 4]> {
+    let rows = input.lines()
+        .skip(2)
+        .take(2)
+        .map(|line| {
+            let row = line.char_indices()
+                .filter(|(_, c)| !matches!(c, '#' | ' '))
+                .map(|(i, c)| {
+                    AmphipodVariety::from_char(c)
+                        .ok_or_else(|| ParseError::at(&line[i..i + c.len_utf8()], "Expected an amphipod (A, B, C or D)"))
+                })
+                .collect::<ParseResult<Vec<_>>>()?;
+            if row.len() != 4 {
+                return Err(ParseError::at(line, "Expected 4 amphipods in the row"));
+            }
+            Ok(row)
+        })
+        .collect::<ParseResult<Vec<_>>>()?;
+    if rows.len() != 2 {
+        return Err(ParseError::new("Could not find 2 rows of amphipods"));
+    }
+    for variety in [A, B, C, D] {
+        if rows.iter().flatten().filter(|&&a| a == variety).count() != 2 {
+            return Err(ParseError::new(format!("Expected exactly 2 amphipods of type {:?}", variety)));
+        }
+    }
+    Ok([0, 1, 2, 3].map(|ri| [rows[0][ri], rows[1][ri]]))
+}
+
+// Part 2 unfolds the diagram, inserting two extra rows between the existing ones:
+//   #D#C#B#A#
+//   #D#B#A#C#
+pub fn unfold_rooms(rooms: [[AmphipodVariety; 2]; 4]) -> [[AmphipodVariety; 4]; 4] {
+    const INSERTED: [[AmphipodVariety; 2]; 4] = [[D, D], [C, B], [B, A], [A, C]];
+    [0, 1, 2, 3].map(|ri| [rooms[ri][0], INSERTED[ri][0], INSERTED[ri][1], rooms[ri][1]])
+}
+
+fn cheapest_solution_cost<const DEPTH: usize>(burrow: Burrow<DEPTH>) -> isize {
+    let soln = dijkstra(&Rearrangement, burrow)
+        .expect("Could not find solution");
+    for (i, burrow) in soln.states.iter().enumerate().skip(1) {
+        trace!("amphipod_moved", "after move {}:\n{}", i, burrow);
+    }
+    soln.cost as isize
+}
+
+pub struct Day23;
+impl Solution for Day23 {
+    type Input = [[AmphipodVariety; 2]; 4];
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> ParseResult<[[AmphipodVariety; 2]; 4]> {
+        parse_rooms(input)
+    }
+
+    fn part1(rooms: &[[AmphipodVariety; 2]; 4]) -> isize {
+        cheapest_solution_cost(Burrow::new(*rooms))
+    }
+
+    fn part2(rooms: &[[AmphipodVariety; 2]; 4]) -> isize {
+        cheapest_solution_cost(Burrow::new(unfold_rooms(*rooms)))
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<isize> {
+    Ok(Day23::part1(&Day23::parse(input)?))
+}
+
+pub fn part2(input: &str) -> ParseResult<isize> {
+    Ok(Day23::part2(&Day23::parse(input)?))
+}
+
+// The cheapest rearrangement of the part 1 example
+/*
+#############
+#...........#
+###A#D#C#A###
+  #C#D#B#B#
+  #########
+
+#############
+#.A.........#
+###.#D#C#A###
+  #C#D#B#B#
+  #########
+
+#############
+#.A........A#
+###.#D#C#.###
+  #C#D#B#B#
+  #########
+
+#############
+#.A.......BA#
+###.#D#C#.###
+  #C#D#B#.#
+  #########
+
+#############
+#.A.......BA#
+###.#.#C#.###
+  #C#D#B#D#
+  #########
+
+#############
+#.A.......BA#
+###.#.#C#D###
+  #C#.#B#D#
+  #########
+
+#############
+#.A........A#
+###.#.#C#D###
+  #C#B#B#D#
+  #########
+
+#############
+#.A.C......A#
+###.#.#.#D###
+  #C#B#B#D#
+  #########
+
+#############
+#.A.C......A#
+###.#B#.#D###
+  #C#B#.#D#
+  #########
+
+#############
+#.A........A#
+###.#B#.#D###
+  #C#B#C#D#
+  #########
+
+#############
+#.A........A#
+###.#B#C#D###
+  #.#B#C#D#
+  #########
+
+#############
+#..........A#
+###.#B#C#D###
+  #A#B#C#D#
+  #########
+
+#############
+#...........#
+###A#B#C#D###
+  #A#B#C#D#
+  #########
+ */
@@ -0,0 +1,153 @@
+use aoc_common::render::{Animation, Frame};
+use aoc_common::solution::NoPuzzle;
+use aoc_common::{Grid, ParseResult, Solution};
+
+#[derive(Clone, Eq, PartialEq)]
+enum Cuke {
+    East,
+    South,
+    None
+}
+
+#[derive(Clone)]
+pub struct Seabed {
+    cukes: Grid<Cuke>,
+}
+
+impl Seabed {
+    pub fn parse(input: &str) -> ParseResult<Seabed> {
+        let cukes = Grid::parse_chars(input, "'>', 'v' or '.'", |c| match c {
+            '>' => Some(Cuke::East),
+            'v' => Some(Cuke::South),
+            '.' => Some(Cuke::None),
+            _ => None,
+        })?;
+        Ok(Seabed { cukes })
+    }
+
+    pub fn steps_to_stopped(&mut self) -> usize {
+        let mut count = 0;
+        loop {
+            let num_moves = self.step();
+            count += 1;
+            if num_moves == 0 {
+                return count;
+            }
+        }
+    }
+
+    fn step(&mut self) -> usize {
+        self.step_herd(Cuke::East, (1, 0)) +
+            self.step_herd(Cuke::South, (0, 1))
+    }
+
+    fn step_herd(&mut self, herd_type: Cuke, (dx, dy): (isize, isize)) -> usize {
+        let mut count = 0;
+        let mut new_cukes = self.cukes.clone();
+
+        for (pos, cuke) in self.cukes.iter() {
+            if *cuke == herd_type {
+                let neighbour = self.cukes.wrapping_offset(&pos, dx, dy);
+                if let Cuke::None = &self.cukes[neighbour] {
+                    new_cukes.swap(&pos, &neighbour);
+                    count += 1;
+                }
+            }
+        }
+
+        self.cukes = new_cukes;
+        count
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.cukes, 3, |cuke| match cuke {
+            Cuke::None => 0,
+            Cuke::East => 1,
+            Cuke::South => 2,
+        })
+    }
+
+    #[allow(dead_code)]
+    fn debug_string(&self) -> String {
+        let mut result = String::new();
+        for row in self.cukes.rows() {
+            for cuke in row {
+                let char = match cuke {
+                    Cuke::East => '>',
+                    Cuke::South => 'v',
+                    Cuke::None => '.',
+                };
+                result.push(char);
+            }
+            result.push('\n');
+        }
+        result
+    }
+}
+
+pub struct Day25;
+impl Solution for Day25 {
+    type Input = Seabed;
+    type Answer1 = usize;
+    type Answer2 = NoPuzzle;
+    const HAS_PART_2: bool = false;
+
+    fn parse(input: &str) -> ParseResult<Seabed> {
+        Seabed::parse(input)
+    }
+
+    fn part1(seabed: &Seabed) -> usize {
+        seabed.clone().steps_to_stopped()
+    }
+
+    fn part2(_seabed: &Seabed) -> NoPuzzle {
+        NoPuzzle
+    }
+}
+
+impl Animation for Day25 {
+    const PALETTE: &'static str = "ocean";
+
+    fn frames(seabed: &Seabed) -> Vec<Frame> {
+        let mut seabed = seabed.clone();
+        let mut frames = vec![seabed.frame()];
+        while seabed.step() > 0 {
+            frames.push(seabed.frame());
+        }
+        frames
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<usize> {
+    Ok(Day25::part1(&Day25::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input;
+    use super::*;
+
+    #[test]
+    fn test_example_step_1() {
+        let mut seabed = Seabed::parse(&input::read_to_string("example")).unwrap();
+        seabed.step();
+        let str = seabed.debug_string();
+        assert_eq!(&str, "....>.>v.>
+v.v>.>v.v.
+>v>>..>v..
+>>v>v>.>.v
+.>v.v...v.
+v>>.>vvv..
+..v...>>..
+vv...>>vv.
+>.v.v..v.v
+")
+    }
+
+    #[test]
+    fn test_example_steps_to_stopped() {
+        let mut seabed = Seabed::parse(&input::read_to_string("example")).unwrap();
+        let steps = seabed.steps_to_stopped();
+        assert_eq!(steps, 58);
+    }
+}
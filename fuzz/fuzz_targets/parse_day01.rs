@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = day01::Day01::parse(input).map_err(|e| e.locate(input));
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = day02::Day02::parse(input).map_err(|e| e.locate(input));
+});